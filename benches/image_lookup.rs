@@ -0,0 +1,155 @@
+//! Measures `Cli::image` against generated image lists of 1, 10, 100 and 1000 images
+//!
+//! Each size is timed four ways so the cost can be split into its parts:
+//!
+//! * `spawn` - `images()` against an empty list, the cost of starting crictl
+//! * `parse` - parsing the JSON in process without crictl
+//! * `images` - `images()`, spawn plus parsing plus deserializing every image
+//! * `image` - `image()` for the digest of the last image, `images` plus the lookup
+//!
+//! It then times `list_all()` against calling `pods()`, `containers_all()` and `images()`
+//! one after the other on the iks mock, to check the three calls overlap.
+//!
+//! Run with `cargo bench --bench image_lookup`.
+
+use libcrio::Cli;
+use serde_json::Value;
+use std::fs;
+use std::hint::black_box;
+use std::os::unix::fs::PermissionsExt;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
+
+const SIZES: [usize; 4] = [1, 10, 100, 1000];
+const PROCESS_RUNS: usize = 30;
+const PARSE_RUNS: usize = 300;
+
+fn main() {
+    let spawn_dir = MockDir::new(0);
+    let spawn_cli = spawn_dir.cli();
+    let spawn = median(PROCESS_RUNS, || {
+        black_box(spawn_cli.images().unwrap());
+    });
+
+    println!(
+        "{:>6} {:>12} {:>12} {:>12} {:>12}",
+        "images", "spawn", "parse", "images", "image"
+    );
+    for n in SIZES {
+        let dir = MockDir::new(n);
+        let cli = dir.cli();
+        let json = images_json(n);
+        let last_digest = digest_ref(n - 1);
+
+        let parse = median(PARSE_RUNS, || {
+            black_box(serde_json::from_str::<Value>(&json).unwrap());
+        });
+        let images = median(PROCESS_RUNS, || {
+            black_box(cli.images().unwrap());
+        });
+        let image = median(PROCESS_RUNS, || {
+            black_box(cli.image(&last_digest).unwrap());
+        });
+        println!(
+            "{:>6} {:>12?} {:>12?} {:>12?} {:>12?}",
+            n, spawn, parse, images, image
+        );
+    }
+
+    let cli = Cli {
+        bin_path: format!("{}/mock/iks", env!("CARGO_MANIFEST_DIR")),
+        ..Default::default()
+    };
+    let sequential = median(PROCESS_RUNS, || {
+        black_box(cli.pods().unwrap());
+        black_box(cli.containers_all().unwrap());
+        black_box(cli.images().unwrap());
+    });
+    let list_all = median(PROCESS_RUNS, || {
+        black_box(cli.list_all().unwrap());
+    });
+    println!();
+    println!("{:>12} {:>12}", "sequential", "list_all");
+    println!("{:>12?} {:>12?}", sequential, list_all);
+}
+
+/// Runs `f` `runs` times and returns the median duration
+fn median(runs: usize, mut f: impl FnMut()) -> Duration {
+    let mut times: Vec<Duration> = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect();
+    times.sort();
+    times[runs / 2]
+}
+
+fn digest(i: usize) -> String {
+    format!("{:064x}", i + 1)
+}
+
+fn digest_ref(i: usize) -> String {
+    format!("registry.example.com/bench/app-{}@sha256:{}", i, digest(i))
+}
+
+/// Returns `crictl img -o json` output with `n` images each with one tag and two digests
+fn images_json(n: usize) -> String {
+    let images: Vec<Value> = (0..n)
+        .map(|i| {
+            serde_json::json!({
+                "id": format!("sha256:{:064x}", i + 0x10000),
+                "repoTags": [format!("registry.example.com/bench/app-{}:1.0", i)],
+                "repoDigests": [
+                    format!("mirror.example.com/bench/app-{}@sha256:{}", i, digest(i)),
+                    digest_ref(i),
+                ],
+                "size": "2801976",
+                "uid": null,
+                "username": ""
+            })
+        })
+        .collect();
+    serde_json::json!({ "images": images }).to_string()
+}
+
+/// A temporary bin_path holding a crictl that prints a generated image list
+struct MockDir {
+    path: PathBuf,
+}
+
+impl MockDir {
+    fn new(n: usize) -> MockDir {
+        let path = std::env::temp_dir().join(format!("libcrio-bench-{}-{}", process::id(), n));
+        fs::create_dir_all(&path).unwrap();
+        fs::write(path.join("images.json"), images_json(n)).unwrap();
+        // Only bash builtins are used as crictl runs with PATH set to the bin_path
+        let crictl = path.join("crictl");
+        fs::write(
+            &crictl,
+            "#!/bin/bash\nif [ \"$1\" = \"img\" ]\nthen\n    echo \"$(<\"${0%/*}/images.json\")\"\nfi\n",
+        )
+        .unwrap();
+        fs::set_permissions(&crictl, fs::Permissions::from_mode(0o755)).unwrap();
+        MockDir { path }
+    }
+
+    fn cli(&self) -> Cli {
+        Cli {
+            bin_path: path_str(&self.path),
+            ..Default::default()
+        }
+    }
+}
+
+impl Drop for MockDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+fn path_str(path: &Path) -> String {
+    path.to_str().unwrap().to_string()
+}
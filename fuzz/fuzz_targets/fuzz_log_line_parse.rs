@@ -0,0 +1,10 @@
+#![no_main]
+
+use libcrio::LogLine;
+use libfuzzer_sys::fuzz_target;
+
+// The message is always the end of the line whether or not it was in the CRI format
+fuzz_target!(|line: &str| {
+    let parsed = LogLine::parse(line);
+    assert!(line.ends_with(&parsed.message));
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use libcrio::CrioError;
+use libfuzzer_sys::fuzz_target;
+
+// Any bytes crictl writes to stdout must parse or give a JsonParse error, never panic
+fuzz_target!(|data: &[u8]| {
+    match libcrio::fuzzing::slice_to_value(data) {
+        Ok(_) | Err(CrioError::JsonParse(_, _)) => {}
+        Err(e) => panic!("unexpected error {:?}", e),
+    }
+});
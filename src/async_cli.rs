@@ -0,0 +1,641 @@
+use crate::{
+    allow_empty_log, ctr, find_image, first_pod, output_to_text, program_command, redact_args,
+    slice_to_value, strip_debug_lines, to_containers, to_os_args, to_pods, Cli, CommandOptions,
+    Container, CrioError, Image, ImageCommand, LogOptions, Pod,
+};
+use futures_core::Stream;
+use serde_json::Value;
+use std::ffi::OsStr;
+use std::fmt;
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::process::ExitStatus;
+use std::task::{ready, Context, Poll};
+use tokio::io::{AsyncBufReadExt, AsyncRead, BufReader, Lines, ReadBuf};
+use tokio::process::{ChildStderr, ChildStdout, Command};
+
+type ExitFuture = Pin<Box<dyn Future<Output = std::io::Result<ExitStatus>> + Send>>;
+
+/// Async versions of the `Cli` commands.
+///
+/// These use `tokio::process::Command` so they don't block a runtime worker thread
+/// while crictl is running.
+impl Cli {
+    /// Async version of `Cli::pod`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libcrio::Cli;
+    /// let bin_path = format!("{}/mock/iks", env!("CARGO_MANIFEST_DIR"));
+    /// let cli = Cli {
+    ///     bin_path,
+    ///     ..Default::default()
+    /// };
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// let val = cli.pod_async("tests").await.unwrap();
+    /// # });
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(bin_path = %self.bin_path)))]
+    pub async fn pod_async(&self, hostname: &str) -> Result<Pod, CrioError> {
+        let pod_output_args = self.command_args(["pods", "--name", hostname, "-o", "json"]);
+        let pod_list =
+            run_command_async(pod_output_args, &self.bin_path, &self.command_options()).await?;
+        first_pod(to_pods(pod_list)?, hostname)
+    }
+
+    /// Async version of `Cli::inspect_pod`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libcrio::Cli;
+    /// let bin_path = format!("{}/mock/iks", env!("CARGO_MANIFEST_DIR"));
+    /// let cli = Cli {
+    ///     bin_path,
+    ///     ..Default::default()
+    /// };
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// let val = cli.inspect_pod_async("51cd8bdaa13a65518e790d307359d33f9288fc82664879c609029b1a83862db6").await.unwrap();
+    /// # });
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(bin_path = %self.bin_path)))]
+    pub async fn inspect_pod_async(&self, pod_id: &str) -> Result<Value, CrioError> {
+        let inspect_output_args = self.command_args(["inspectp", pod_id]);
+        run_command_async(inspect_output_args, &self.bin_path, &self.command_options()).await
+    }
+
+    /// Async version of `Cli::pod_containers`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libcrio::Cli;
+    /// let bin_path = format!("{}/mock/iks", env!("CARGO_MANIFEST_DIR"));
+    /// let cli = Cli {
+    ///     bin_path,
+    ///     ..Default::default()
+    /// };
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// let val = cli.pod_containers_async("51cd8bdaa13a65518e790d307359d33f9288fc82664879c609029b1a83862db6").await.unwrap();
+    /// # });
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(bin_path = %self.bin_path)))]
+    pub async fn pod_containers_async(&self, pod_id: &str) -> Result<Vec<Container>, CrioError> {
+        let ps_output_args = self.command_args(["ps", "-o", "json", "-p", pod_id]);
+        let container_list =
+            run_command_async(ps_output_args, &self.bin_path, &self.command_options()).await?;
+        to_containers(container_list)
+    }
+
+    /// Async version of `Cli::inspect_container`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libcrio::Cli;
+    /// let bin_path = format!("{}/mock/iks", env!("CARGO_MANIFEST_DIR"));
+    /// let cli = Cli {
+    ///     bin_path,
+    ///     ..Default::default()
+    /// };
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// let val = cli.inspect_container_async("765312810c818bca4836c3598e21471bfd96be8ca84ca952290a9900b7c055a7").await.unwrap();
+    /// # });
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(bin_path = %self.bin_path)))]
+    pub async fn inspect_container_async(&self, container_id: &str) -> Result<Value, CrioError> {
+        let inspect_output_args = self.command_args(["inspect", container_id]);
+        run_command_async(inspect_output_args, &self.bin_path, &self.command_options()).await
+    }
+
+    /// Async version of `Cli::image`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libcrio::Cli;
+    /// let bin_path = format!("{}/mock/iks", env!("CARGO_MANIFEST_DIR"));
+    /// let cli = Cli {
+    ///     bin_path,
+    ///     ..Default::default()
+    /// };
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// let val = cli.image_async("sha256:3b8adc6c30f4e7e4afb57daef9d1c8af783a4a647a4670780e9df085c0525efa").await.unwrap();
+    /// # });
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(bin_path = %self.bin_path)))]
+    pub async fn image_async(&self, image_ref: &str) -> Result<Image, CrioError> {
+        let image_list = match self.image_command {
+            ImageCommand::Ctr => {
+                let ctr_output_args = self.ctr_image_args();
+                let image_list = run_program_text_async(
+                    "ctr",
+                    ctr_output_args,
+                    &self.bin_path,
+                    &self.command_options(),
+                )
+                .await?;
+                ctr::images_to_value(&image_list)
+            }
+            _ => {
+                let image_output_args =
+                    self.command_args([self.image_command.to_string().as_str(), "-o", "json"]);
+                run_command_async(image_output_args, &self.bin_path, &self.command_options())
+                    .await?
+            }
+        };
+        find_image(&image_list, image_ref, &[self.image_command.to_string()])
+    }
+
+    /// Async version of `Cli::tail_logs`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libcrio::Cli;
+    /// let bin_path = format!("{}/mock/iks", env!("CARGO_MANIFEST_DIR"));
+    /// let cli = Cli {
+    ///     bin_path,
+    ///     ..Default::default()
+    /// };
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// #[allow(deprecated)]
+    /// let val = cli.tail_logs_async("sha256:3b8adc6c30f4e7e4afb57daef9d1c8af783a4a647a4670780e9df085c0525efa", 500).await.unwrap();
+    /// # });
+    /// ```
+    #[deprecated(note = "use logs_with_options_async")]
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(bin_path = %self.bin_path)))]
+    pub async fn tail_logs_async(
+        &self,
+        container_id: &str,
+        line_count: u32,
+    ) -> Result<String, CrioError> {
+        let opts = LogOptions::builder().tail(line_count).build();
+        self.logs_with_options_async(container_id, &opts).await
+    }
+
+    /// Async version of `Cli::logs_with_options`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libcrio::{Cli, LogOptions};
+    /// let bin_path = format!("{}/mock/iks", env!("CARGO_MANIFEST_DIR"));
+    /// let cli = Cli {
+    ///     bin_path,
+    ///     ..Default::default()
+    /// };
+    /// let opts = LogOptions::builder().tail(100).build();
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// let val = cli.logs_with_options_async("sha256:3b8adc6c30f4e7e4afb57daef9d1c8af783a4a647a4670780e9df085c0525efa", &opts).await.unwrap();
+    /// # });
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(bin_path = %self.bin_path)))]
+    pub async fn logs_with_options_async(
+        &self,
+        container_id: &str,
+        opts: &LogOptions,
+    ) -> Result<String, CrioError> {
+        let opt_args = opts.to_args();
+        let mut log_args = vec!["logs"];
+        log_args.extend(opt_args.iter().map(String::as_str));
+        log_args.push(container_id);
+        let log_output_args = self.command_args(&log_args);
+        allow_empty_log(
+            run_command_text_async(log_output_args, &self.bin_path, &self.command_options()).await,
+        )
+    }
+}
+
+/// The lines of a followed container log, created with `Cli::log_stream`
+///
+/// A `Stream` of the lines, or call `next` until it returns `None`. crictl is killed when this is dropped.
+pub struct AsyncLogLines {
+    exit: Option<ExitFuture>,
+    lines: Option<Lines<BufReader<ChildStdout>>>,
+    stderr: Option<ChildStderr>,
+    stderr_buf: Vec<u8>,
+    spawn_error: Option<CrioError>,
+    debug_mode: bool,
+}
+
+impl fmt::Debug for AsyncLogLines {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncLogLines")
+            .field("running", &self.exit.is_some())
+            .field("lines", &self.lines)
+            .field("stderr", &self.stderr)
+            .field("stderr_buf", &self.stderr_buf)
+            .field("spawn_error", &self.spawn_error)
+            .field("debug_mode", &self.debug_mode)
+            .finish()
+    }
+}
+
+impl AsyncLogLines {
+    /// Waits for the next log line
+    ///
+    /// Returns `None` once crictl has exited. If crictl failed the last item is the error.
+    pub async fn next(&mut self) -> Option<Result<String, CrioError>> {
+        poll_fn(|cx| self.poll_item(cx)).await
+    }
+
+    fn poll_item(&mut self, cx: &mut Context<'_>) -> Poll<Option<Result<String, CrioError>>> {
+        if let Some(e) = self.spawn_error.take() {
+            return Poll::Ready(Some(Err(e)));
+        }
+        if self.lines.is_some() {
+            match ready!(self.poll_line(cx)) {
+                Ok(Some(line)) => return Poll::Ready(Some(Ok(line))),
+                Ok(None) => self.lines = None,
+                Err(e) => {
+                    self.lines = None;
+                    return Poll::Ready(Some(Err(CrioError::Io(e))));
+                }
+            }
+        }
+        self.poll_finish(cx).map(|result| result.err().map(Err))
+    }
+
+    /// Polls for a line while draining stderr so a chatty container can't block crictl
+    fn poll_line(&mut self, cx: &mut Context<'_>) -> Poll<std::io::Result<Option<String>>> {
+        // A failed stderr read only loses the error message, the lines can still be read
+        let _ = self.poll_stderr(cx);
+        match self.lines.as_mut() {
+            Some(lines) => Pin::new(lines).poll_next_line(cx),
+            None => Poll::Ready(Ok(None)),
+        }
+    }
+
+    /// Reads stderr into `stderr_buf` until it would block, returning ready once it is closed
+    fn poll_stderr(&mut self, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        while let Some(stderr) = self.stderr.as_mut() {
+            let mut buf = [0u8; 4096];
+            let mut read_buf = ReadBuf::new(&mut buf);
+            match ready!(Pin::new(stderr).poll_read(cx, &mut read_buf)) {
+                Ok(()) if !read_buf.filled().is_empty() => {
+                    self.stderr_buf.extend_from_slice(read_buf.filled());
+                }
+                Ok(()) => self.stderr = None,
+                Err(e) => {
+                    self.stderr = None;
+                    return Poll::Ready(Err(e));
+                }
+            }
+        }
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_finish(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), CrioError>> {
+        if self.exit.is_none() {
+            return Poll::Ready(Ok(()));
+        }
+        if let Err(e) = ready!(self.poll_stderr(cx)) {
+            self.exit = None;
+            return Poll::Ready(Err(CrioError::Io(e)));
+        }
+        let status = match self.exit.as_mut() {
+            Some(exit) => ready!(exit.as_mut().poll(cx)),
+            None => return Poll::Ready(Ok(())),
+        };
+        self.exit = None;
+        let status = status?;
+        let stderr = strip_debug_lines(
+            self.debug_mode,
+            String::from_utf8_lossy(&self.stderr_buf).into_owned(),
+        );
+        if status.success() || stderr.is_empty() {
+            return Poll::Ready(Ok(()));
+        }
+        Poll::Ready(Err(CrioError::NonZeroExit {
+            code: status.code().unwrap_or(-1),
+            stderr,
+        }))
+    }
+}
+
+impl Stream for AsyncLogLines {
+    type Item = Result<String, CrioError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.get_mut().poll_item(cx)
+    }
+}
+
+impl Cli {
+    /// Follows the logs of a container yielding each line as crictl writes it
+    ///
+    /// `--follow` is always passed whatever `opts.follow` is set to.
+    /// The lines end when the container stops. `command_timeout` isn't applied.
+    /// If crictl can't be started the first item is the error.
+    ///
+    /// # Arguments
+    ///
+    /// * `container_id` - The container_id related to one of the containers obtained from `pod_containers`
+    ///
+    /// * `opts` - The `LogOptions` to pass to crictl
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libcrio::{Cli, LogOptions};
+    /// let bin_path = format!("{}/mock/follow_logs", env!("CARGO_MANIFEST_DIR"));
+    /// let cli = Cli {
+    ///     bin_path,
+    ///     ..Default::default()
+    /// };
+    /// # tokio::runtime::Builder::new_current_thread().enable_all().build().unwrap().block_on(async {
+    /// let mut lines = cli.log_stream("765312810c818bca4836c3598e21471bfd96be8ca84ca952290a9900b7c055a7", &LogOptions::default());
+    /// while let Some(line) = lines.next().await {
+    ///     println!("{}", line.unwrap());
+    /// }
+    /// # });
+    /// ```
+    #[cfg_attr(feature = "tracing", tracing::instrument(skip(self), fields(bin_path = %self.bin_path)))]
+    pub fn log_stream(&self, container_id: &str, opts: &LogOptions) -> AsyncLogLines {
+        let opt_args = LogOptions {
+            follow: false,
+            ..opts.clone()
+        }
+        .to_args();
+        let mut log_args = vec!["logs", "--follow"];
+        log_args.extend(opt_args.iter().map(String::as_str));
+        log_args.push(container_id);
+        let log_output_args = self.command_args(&log_args);
+        let spawned = Command::from(program_command(
+            "crictl",
+            &log_output_args,
+            &self.bin_path,
+            &self.command_options(),
+        ))
+        .kill_on_drop(true)
+        .spawn();
+        match spawned {
+            Ok(mut child) => AsyncLogLines {
+                lines: child.stdout.take().map(|out| BufReader::new(out).lines()),
+                stderr: child.stderr.take(),
+                // Owning the child in the future keeps kill_on_drop working when this is dropped
+                exit: Some(Box::pin(async move { child.wait().await })),
+                stderr_buf: vec![],
+                spawn_error: None,
+                debug_mode: self.debug_mode,
+            },
+            Err(e) => AsyncLogLines {
+                exit: None,
+                lines: None,
+                stderr: None,
+                stderr_buf: vec![],
+                spawn_error: Some(CrioError::SpawnFailed(format!(
+                    "{:?} {}",
+                    log_output_args, e
+                ))),
+                debug_mode: self.debug_mode,
+            },
+        }
+    }
+}
+
+async fn run_command_text_async(
+    args: impl IntoIterator<Item = impl AsRef<OsStr>>,
+    bin_path: &str,
+    options: &CommandOptions,
+) -> Result<String, CrioError> {
+    run_program_text_async("crictl", args, bin_path, options).await
+}
+
+async fn run_program_text_async(
+    program: &str,
+    args: impl IntoIterator<Item = impl AsRef<OsStr>>,
+    bin_path: &str,
+    options: &CommandOptions,
+) -> Result<String, CrioError> {
+    let args = to_os_args(args);
+    let cmd = match Command::from(program_command(program, &args, bin_path, options))
+        .kill_on_drop(true)
+        .spawn()
+    {
+        Ok(v) => v,
+        Err(e) => {
+            return Err(CrioError::SpawnFailed(format!(
+                "{:?} {}",
+                redact_args(&args),
+                e
+            )));
+        }
+    };
+    let waiter = match options.timeout {
+        Some(t) => match tokio::time::timeout(t, cmd.wait_with_output()).await {
+            Ok(v) => v?,
+            Err(_) => return Err(CrioError::Timeout(t)),
+        },
+        None => cmd.wait_with_output().await?,
+    };
+    output_to_text(waiter, &args, options.warn_on_stderr)
+}
+
+async fn run_command_async(
+    args: impl IntoIterator<Item = impl AsRef<OsStr>>,
+    bin_path: &str,
+    options: &CommandOptions,
+) -> Result<Value, CrioError> {
+    let args = to_os_args(args);
+    let str_ok = run_command_text_async(&args, bin_path, options).await?;
+    slice_to_value(str_ok.as_bytes(), &args)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Cli, CrioError, ImageCommand, LogOptions};
+    use futures_core::Stream;
+    use std::future::poll_fn;
+    use std::pin::Pin;
+    use std::time::Duration;
+
+    fn block_on<F: std::future::Future>(f: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(f)
+    }
+
+    fn get_cli(mock: &str) -> Cli {
+        let bin_path = format!("{}/mock/{}", env!("CARGO_MANIFEST_DIR"), mock);
+        Cli {
+            bin_path,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_pod_async() {
+        let cli = get_cli("iks");
+        let val = block_on(cli.pod_async("tests")).unwrap();
+        assert_eq!(
+            val.id,
+            "51cd8bdaa13a65518e790d307359d33f9288fc82664879c609029b1a83862db6"
+        );
+    }
+
+    #[test]
+    fn test_inspect_pod_async() {
+        let cli = get_cli("openshift");
+        let val =
+            block_on(cli.inspect_pod_async(
+                "134b58ab2e0cfd7432a9db818b1b4ec52fdc747333f0ba2c9342860dc2ea7c50",
+            ))
+            .unwrap();
+        assert_eq!(val["info"]["pid"].as_i64().unwrap(), 38091)
+    }
+
+    #[test]
+    fn test_image_async() {
+        let cli = get_cli("iks");
+        let val = block_on(cli.image_async(
+            "sha256:3b8adc6c30f4e7e4afb57daef9d1c8af783a4a647a4670780e9df085c0525efa",
+        ))
+        .unwrap();
+        assert_eq!(val.size, 338054458)
+    }
+
+    #[test]
+    fn test_image_ctr_async() {
+        let cli = Cli {
+            image_command: ImageCommand::Ctr,
+            ..get_cli("ctr")
+        };
+        let val = block_on(cli.image_async("docker.io/library/busybox:latest")).unwrap();
+        assert_eq!(
+            val.id,
+            "sha256:388056c9a6838deea3792e8f00705b35b439cf57b3c9c2634fb4e95cfc896de6"
+        );
+    }
+
+    #[allow(deprecated)]
+    #[test]
+    fn test_tail_logs_async() {
+        let bin_path = format!("{}/mock/long_logs:/usr/bin", env!("CARGO_MANIFEST_DIR"));
+        let cli = Cli {
+            bin_path,
+            ..Default::default()
+        };
+        let val = block_on(cli.tail_logs_async("tests", 500)).unwrap();
+        assert_eq!(val.lines().count(), 500);
+    }
+
+    #[test]
+    fn test_logs_with_options_async() {
+        let bin_path = format!("{}/mock/long_logs:/usr/bin", env!("CARGO_MANIFEST_DIR"));
+        let cli = Cli {
+            bin_path,
+            ..Default::default()
+        };
+        let opts = LogOptions::builder().tail(20).build();
+        let val = block_on(cli.logs_with_options_async("tests", &opts)).unwrap();
+        assert_eq!(val.lines().count(), 20);
+    }
+
+    #[test]
+    fn test_async_command_timeout() {
+        let bin_path = format!("{}/mock/slow:/usr/bin:/bin", env!("CARGO_MANIFEST_DIR"));
+        let cli = Cli {
+            bin_path,
+            command_timeout: Some(Duration::from_millis(200)),
+            ..Default::default()
+        };
+        let val = block_on(cli.pod_async("tests"));
+        assert!(matches!(val, Err(CrioError::Timeout(_))));
+    }
+
+    #[test]
+    fn test_log_stream() {
+        let cli = get_cli("follow_logs");
+        let opts = LogOptions::builder().timestamps(true).build();
+        let lines = block_on(async {
+            let mut stream = cli.log_stream("tests", &opts);
+            let mut lines = vec![];
+            while lines.len() < 5 {
+                match stream.next().await {
+                    Some(line) => lines.push(line.unwrap()),
+                    None => break,
+                }
+            }
+            lines
+        });
+        assert_eq!(lines, ["line 1", "line 2", "line 3", "line 4", "line 5"]);
+    }
+
+    #[test]
+    fn test_log_stream_poll_next() {
+        let cli = get_cli("follow_logs");
+        let lines = block_on(async {
+            let mut stream = cli.log_stream("tests", &LogOptions::default());
+            let mut lines = vec![];
+            while let Some(line) = poll_fn(|cx| Pin::new(&mut stream).poll_next(cx)).await {
+                lines.push(line.unwrap());
+            }
+            lines
+        });
+        assert_eq!(lines.len(), 10);
+        assert_eq!(lines[0], "line 1");
+    }
+
+    #[test]
+    fn test_log_stream_ends() {
+        let cli = get_cli("follow_logs");
+        let count = block_on(async {
+            let mut stream = cli.log_stream("tests", &LogOptions::default());
+            let mut count = 0;
+            while let Some(line) = stream.next().await {
+                line.unwrap();
+                count += 1;
+            }
+            count
+        });
+        assert_eq!(count, 10);
+    }
+
+    #[test]
+    fn test_log_stream_errors() {
+        let cli = get_cli("follow_logs");
+        let items = block_on(async {
+            let mut stream = cli.log_stream("doesnotexist", &LogOptions::default());
+            let mut items = vec![];
+            while let Some(item) = stream.next().await {
+                items.push(item);
+            }
+            items
+        });
+        assert_eq!(items.len(), 1);
+        assert!(matches!(
+            items[0],
+            Err(CrioError::NonZeroExit { code: 1, .. })
+        ));
+
+        let cli = get_cli("doesnotexist");
+        let mut items = vec![];
+        block_on(async {
+            let mut stream = cli.log_stream("tests", &LogOptions::default());
+            while let Some(item) = stream.next().await {
+                items.push(item);
+            }
+        });
+        assert_eq!(items.len(), 1);
+        assert!(matches!(items[0], Err(CrioError::SpawnFailed(_))));
+    }
+
+    #[test]
+    fn test_async_mixed_errors() {
+        let cli = get_cli("mixed_errors");
+        let val = block_on(cli.pod_containers_async("tests"));
+        assert!(matches!(val, Err(CrioError::StderrNonEmpty(_))));
+    }
+
+    #[test]
+    fn test_async_warn_on_stderr() {
+        let cli = get_cli("mixed_warnings").with_warn_on_stderr(true);
+        let val = block_on(cli.pod_async("tests")).unwrap();
+        assert_eq!(val.metadata.name, "tests");
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{Cli, CrictlVersion, CrioError, ImageCommand};
+use std::path::Path;
+use std::time::Duration;
+
+/// A builder for `Cli`
+///
+/// Created with `Cli::builder()` and pre-populated with the values from `Cli::default()`.
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::Cli;
+/// let bin_path = format!("{}/mock/iks", env!("CARGO_MANIFEST_DIR"));
+/// let cli = Cli::builder().bin_path(bin_path).build().unwrap();
+/// let val = cli.pod("tests").unwrap();
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CliBuilder {
+    cli: Cli,
+}
+
+impl CliBuilder {
+    /// Sets the bin_path used to find crictl
+    pub fn bin_path(mut self, bin_path: impl Into<String>) -> Self {
+        self.cli.bin_path = bin_path.into();
+        self
+    }
+
+    /// Sets the location of the crictl.yaml
+    pub fn config_path(mut self, config_path: impl Into<String>) -> Self {
+        self.cli.config_path = Some(config_path.into());
+        self
+    }
+
+    /// Sets the command for listing images
+    pub fn image_command(mut self, image_command: ImageCommand) -> Self {
+        self.cli.image_command = image_command;
+        self
+    }
+
+    /// Sets the runtime endpoint passed to crictl with `-r`
+    pub fn runtime_endpoint(mut self, runtime_endpoint: impl Into<String>) -> Self {
+        self.cli.runtime_endpoint = Some(runtime_endpoint.into());
+        self
+    }
+
+    /// Sets the image endpoint passed to crictl with `-i`
+    pub fn image_endpoint(mut self, image_endpoint: impl Into<String>) -> Self {
+        self.cli.image_endpoint = Some(image_endpoint.into());
+        self
+    }
+
+    /// Sets how long to wait for a crictl command before killing it
+    pub fn command_timeout(mut self, command_timeout: Duration) -> Self {
+        self.cli.command_timeout = Some(command_timeout);
+        self
+    }
+
+    /// Sets whether `--debug` is passed to crictl
+    pub fn debug_mode(mut self, debug_mode: bool) -> Self {
+        self.cli.debug_mode = debug_mode;
+        self
+    }
+
+    /// Sets the containerd namespace ctr lists images from, crictl can't be scoped by it
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.cli.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Sets whether stderr from a successful crictl call is logged as a warning rather than returned as an error
+    pub fn warn_on_stderr(mut self, warn_on_stderr: bool) -> Self {
+        self.cli.warn_on_stderr = warn_on_stderr;
+        self
+    }
+
+    /// Sets the version of crictl so calls that depend on it don't need to detect it
+    pub fn crictl_version(mut self, crictl_version: CrictlVersion) -> Self {
+        self.cli.crictl_version = Some(crictl_version);
+        self
+    }
+
+    /// Returns the configured `Cli`
+    ///
+    /// Returns `CrioError::NotFound` if none of the directories in bin_path exist
+    /// or if a config_path has been given that doesn't exist.
+    pub fn build(self) -> Result<Cli, CrioError> {
+        if !self.cli.bin_path.split(':').any(|p| Path::new(p).is_dir()) {
+            return Err(CrioError::NotFound(format!(
+                "bin_path {}",
+                self.cli.bin_path
+            )));
+        }
+        if let Some(config_path) = &self.cli.config_path {
+            if !Path::new(config_path).exists() {
+                return Err(CrioError::NotFound(format!("config_path {}", config_path)));
+            }
+        }
+        Ok(self.cli)
+    }
+}
@@ -0,0 +1,54 @@
+/// The options for a `Cli::checkpoint_with_options` call
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::CheckpointOptions;
+/// let opts = CheckpointOptions::builder("/var/lib/checkpoints/web.tar").build();
+/// assert_eq!(opts.export_path, "/var/lib/checkpoints/web.tar");
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CheckpointOptions {
+    /// Where the checkpoint archive is written
+    pub export_path: String,
+    /// The namespace of the container.
+    /// crictl has no flag for this yet so it isn't passed on.
+    pub namespace: Option<String>,
+}
+
+impl CheckpointOptions {
+    /// Returns a `CheckpointOptionsBuilder` exporting to `export_path`
+    pub fn builder(export_path: impl Into<String>) -> CheckpointOptionsBuilder {
+        CheckpointOptionsBuilder {
+            opts: CheckpointOptions {
+                export_path: export_path.into(),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub(crate) fn to_args(&self) -> Vec<String> {
+        vec![format!("--export={}", self.export_path)]
+    }
+}
+
+/// A builder for `CheckpointOptions`
+///
+/// Created with `CheckpointOptions::builder()`.
+#[derive(Debug, Clone)]
+pub struct CheckpointOptionsBuilder {
+    opts: CheckpointOptions,
+}
+
+impl CheckpointOptionsBuilder {
+    /// Sets the namespace of the container
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.opts.namespace = Some(namespace.into());
+        self
+    }
+
+    /// Returns the configured `CheckpointOptions`
+    pub fn build(self) -> CheckpointOptions {
+        self.opts
+    }
+}
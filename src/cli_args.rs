@@ -0,0 +1,55 @@
+use crate::{Cli, ImageCommand};
+use std::convert::Infallible;
+
+/// The `Cli` settings as command line flags, for flattening into the clap arguments of a tool
+///
+/// Flags that aren't given keep their `Cli::default()` values.
+///
+/// # Examples
+///
+/// ```
+/// use clap::Parser;
+/// use libcrio::{Cli, CliArgs, ImageCommand};
+///
+/// #[derive(Parser)]
+/// struct Args {
+///     #[command(flatten)]
+///     cli_args: CliArgs,
+///     pod: String,
+/// }
+///
+/// let args = Args::parse_from(["podinfo", "--bin-path", "/usr/local/bin", "--image-command", "images", "nginx"]);
+/// let cli = Cli::from(args.cli_args);
+/// assert_eq!(cli.bin_path, "/usr/local/bin");
+/// assert_eq!(cli.image_command, ImageCommand::Images);
+/// assert_eq!(cli.config_path, None);
+/// ```
+#[derive(clap::Args, Debug, Clone, PartialEq, Default)]
+pub struct CliArgs {
+    /// The PATH used to find crictl
+    #[arg(long)]
+    pub bin_path: Option<String>,
+    /// The location of the crictl.yaml
+    #[arg(long)]
+    pub config_path: Option<String>,
+    /// The command for listing images e.g. img, images or ctr
+    #[arg(long, value_parser = parse_image_command)]
+    pub image_command: Option<ImageCommand>,
+}
+
+impl From<CliArgs> for Cli {
+    fn from(args: CliArgs) -> Cli {
+        let cli = Cli::default();
+        Cli {
+            bin_path: args.bin_path.unwrap_or(cli.bin_path),
+            config_path: args.config_path,
+            image_command: args.image_command.unwrap_or(cli.image_command),
+            ..cli
+        }
+    }
+}
+
+/// Accepts any image command, like the config, with unknown ones run as a custom subcommand
+fn parse_image_command(s: &str) -> Result<ImageCommand, Infallible> {
+    Ok(ImageCommand::try_from(s).unwrap_or_else(|_| ImageCommand::Custom(s.to_string())))
+}
@@ -0,0 +1,163 @@
+use crate::debug;
+use chrono::DateTime;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{json, Value};
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+
+/// A container as returned by `crictl ps`
+///
+/// Equality and hashing only use `id` so two values for the same container are equal
+/// even if their other fields differ. This lets them be deduplicated in a `HashSet`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Container {
+    pub id: String,
+    pub pod_sandbox_id: String,
+    #[serde(default)]
+    pub metadata: ContainerMetadata,
+    #[serde(default)]
+    pub image: ImageSpec,
+    #[serde(default)]
+    pub image_ref: String,
+    pub state: ContainerState,
+    /// Nanoseconds since the unix epoch
+    #[serde(default, deserialize_with = "crate::de::from_str_or_num")]
+    pub created_at: i64,
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+    /// Any fields not mapped above
+    #[serde(flatten)]
+    pub raw: Value,
+}
+
+impl PartialEq for Container {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Container {}
+
+impl Hash for Container {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+impl Container {
+    /// Returns true if the container is in the `Running` state
+    pub fn is_running(&self) -> bool {
+        self.state == ContainerState::Running
+    }
+
+    /// Returns the name of the container, or "" when crictl didn't report one
+    pub fn name(&self) -> &str {
+        if self.metadata.name.is_empty() {
+            debug!("container {} has no name in its metadata", self.id);
+        }
+        &self.metadata.name
+    }
+
+    /// Builds a `Container` from `crictl inspect` output
+    ///
+    /// inspect nests the container under `status`, keeps the pod id in `info.sandboxID`
+    /// and writes `createdAt` as an RFC3339 time rather than nanoseconds.
+    pub(crate) fn from_inspect(inspect: &Value) -> Result<Container, serde_json::Error> {
+        let mut status = inspect["status"].clone();
+        if let Some(status) = status.as_object_mut() {
+            status.insert(
+                "podSandboxId".to_string(),
+                inspect["info"]["sandboxID"].clone(),
+            );
+            if let Some(created_at) = status
+                .get("createdAt")
+                .and_then(Value::as_str)
+                .and_then(|c| DateTime::parse_from_rfc3339(c).ok())
+                .and_then(|c| c.timestamp_nanos_opt())
+            {
+                status.insert("createdAt".to_string(), json!(created_at));
+            }
+        }
+        Container::deserialize(status)
+    }
+}
+
+/// The metadata of a container
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct ContainerMetadata {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub attempt: u32,
+}
+
+impl fmt::Display for ContainerMetadata {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// The image a container was created from
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct ImageSpec {
+    #[serde(default)]
+    pub image: String,
+    #[serde(default)]
+    pub annotations: HashMap<String, String>,
+}
+
+/// The state of a container
+///
+/// Parsed from the crictl strings e.g. "CONTAINER_RUNNING".
+/// A state this version doesn't know about becomes `Unknown` rather than an error.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerState {
+    #[serde(rename = "CONTAINER_CREATED")]
+    Created,
+    #[serde(rename = "CONTAINER_RUNNING")]
+    Running,
+    #[serde(rename = "CONTAINER_EXITED")]
+    Exited,
+    #[serde(rename = "CONTAINER_UNKNOWN")]
+    Unknown,
+}
+
+impl ContainerState {
+    /// The value crictl takes for `ps --state`
+    pub(crate) fn crictl_filter(&self) -> &'static str {
+        match self {
+            ContainerState::Created => "created",
+            ContainerState::Running => "running",
+            ContainerState::Exited => "exited",
+            ContainerState::Unknown => "unknown",
+        }
+    }
+}
+
+impl FromStr for ContainerState {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "CONTAINER_CREATED" => ContainerState::Created,
+            "CONTAINER_RUNNING" => ContainerState::Running,
+            "CONTAINER_EXITED" => ContainerState::Exited,
+            "CONTAINER_UNKNOWN" => ContainerState::Unknown,
+            _ => {
+                debug!("unrecognised container state {}", s);
+                ContainerState::Unknown
+            }
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for ContainerState {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or(ContainerState::Unknown))
+    }
+}
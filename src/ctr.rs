@@ -0,0 +1,57 @@
+use serde_json::{json, Map, Value};
+
+/// Converts the table printed by `ctr images list` into the JSON shape of `crictl img -o json`
+///
+/// ctr prints a row per reference so the rows are grouped by manifest digest.
+/// The `sha256:<id>` reference containerd keeps for CRI images becomes the id,
+/// otherwise the manifest digest is used.
+pub(crate) fn images_to_value(list: &str) -> Value {
+    let mut images: Vec<Map<String, Value>> = vec![];
+    for line in list.lines().skip_while(|l| !l.starts_with("REF")).skip(1) {
+        let cols: Vec<&str> = line.split_whitespace().collect();
+        // REF TYPE DIGEST SIZE UNIT PLATFORMS LABELS
+        if cols.len() < 5 {
+            continue;
+        }
+        let (image_ref, digest) = (cols[0], cols[2]);
+        let image = match images.iter_mut().find(|i| i["digest"] == digest) {
+            Some(i) => i,
+            None => {
+                let mut i = Map::new();
+                i.insert("digest".to_string(), json!(digest));
+                i.insert("id".to_string(), json!(digest));
+                i.insert("repoTags".to_string(), json!([]));
+                i.insert("repoDigests".to_string(), json!([]));
+                i.insert("size".to_string(), json!(parse_size(cols[3], cols[4])));
+                images.push(i);
+                images.last_mut().unwrap()
+            }
+        };
+        if image_ref.starts_with("sha256:") {
+            image.insert("id".to_string(), json!(image_ref));
+        } else {
+            let key = if image_ref.contains('@') {
+                "repoDigests"
+            } else {
+                "repoTags"
+            };
+            if let Some(refs) = image[key].as_array_mut() {
+                refs.push(json!(image_ref));
+            }
+        }
+    }
+    json!({ "images": images })
+}
+
+/// Turns the human readable size ctr prints e.g. "2.7 MiB" back into bytes
+fn parse_size(value: &str, unit: &str) -> u64 {
+    let power = match unit {
+        "KiB" => 1,
+        "MiB" => 2,
+        "GiB" => 3,
+        "TiB" => 4,
+        _ => 0,
+    };
+    let value: f64 = value.parse().unwrap_or_default();
+    (value * 1024f64.powi(power)) as u64
+}
@@ -0,0 +1,41 @@
+use serde::{Deserialize, Deserializer};
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StrOrNum<T> {
+    Str(String),
+    Num(T),
+}
+
+/// crictl encodes some numeric fields such as `createdAt` and `size` as JSON strings.
+/// Accepts either a string or a number and parses it into `T`.
+pub(crate) fn from_str_or_num<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr + Deserialize<'de>,
+    T::Err: Display,
+{
+    match StrOrNum::<T>::deserialize(deserializer)? {
+        StrOrNum::Str(s) => s.parse::<T>().map_err(serde::de::Error::custom),
+        StrOrNum::Num(n) => Ok(n),
+    }
+}
+
+#[derive(Deserialize)]
+struct UInt64Value {
+    #[serde(deserialize_with = "from_str_or_num")]
+    value: u64,
+}
+
+/// CRI wraps optional counters such as `usedBytes` in `{"value": "<n>"}`.
+/// Unwraps the value, treating a missing or null wrapper as 0.
+pub(crate) fn from_uint64_value<'de, D>(deserializer: D) -> Result<u64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Ok(Option::<UInt64Value>::deserialize(deserializer)?
+        .map(|v| v.value)
+        .unwrap_or_default())
+}
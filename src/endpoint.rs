@@ -0,0 +1,83 @@
+use crate::{debug, CrioError};
+use std::fs;
+use std::io;
+
+/// Checks a runtime endpoint before crictl tries to connect to it
+///
+/// crictl reports a missing socket or one it can't open as a failed gRPC connection
+/// after its connect timeout, this reports what is wrong with the path straight away.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RuntimeEndpointValidator;
+
+impl RuntimeEndpointValidator {
+    /// Checks the socket of an endpoint exists and can be read and written by this process
+    ///
+    /// A `unix://` prefix is removed and a path without a scheme is treated as a unix socket
+    /// like crictl does. Other schemes such as `tcp://` aren't local so they aren't checked.
+    ///
+    /// Returns `CrioError::SocketNotFound` if there is nothing at the path,
+    /// `CrioError::SocketPermissionDenied` if it can't be opened for reading and writing
+    /// and `CrioError::InvalidArgument` if it isn't a socket.
+    ///
+    /// # Arguments
+    ///
+    /// * `socket_path` - The endpoint e.g. "unix:///run/containerd/containerd.sock"
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libcrio::{CrioError, RuntimeEndpointValidator};
+    /// let val = RuntimeEndpointValidator::check("unix:///does/not/exist.sock");
+    /// assert!(matches!(val, Err(CrioError::SocketNotFound(_))));
+    /// assert!(RuntimeEndpointValidator::check("tcp://10.0.0.7:5000").is_ok());
+    /// ```
+    pub fn check(socket_path: &str) -> Result<(), CrioError> {
+        let path = match socket_path.split_once("://") {
+            Some(("unix", path)) => path,
+            Some((scheme, _)) => {
+                debug!("not checking {} endpoint {}", scheme, socket_path);
+                return Ok(());
+            }
+            None => socket_path,
+        };
+        let metadata = fs::metadata(path).map_err(|e| socket_error(e, path))?;
+        if !is_socket(&metadata) {
+            return Err(CrioError::InvalidArgument(format!(
+                "{} is not a socket",
+                path
+            )));
+        }
+        check_read_write(path)
+    }
+}
+
+fn socket_error(e: io::Error, path: &str) -> CrioError {
+    match e.kind() {
+        io::ErrorKind::NotFound => CrioError::SocketNotFound(path.to_string()),
+        io::ErrorKind::PermissionDenied => CrioError::SocketPermissionDenied(path.to_string()),
+        _ => CrioError::Io(e),
+    }
+}
+
+#[cfg(unix)]
+fn is_socket(metadata: &fs::Metadata) -> bool {
+    use std::os::unix::fs::FileTypeExt;
+    metadata.file_type().is_socket()
+}
+
+#[cfg(not(unix))]
+fn is_socket(_metadata: &fs::Metadata) -> bool {
+    true
+}
+
+#[cfg(unix)]
+fn check_read_write(path: &str) -> Result<(), CrioError> {
+    use nix::unistd::{access, AccessFlags};
+    access(path, AccessFlags::R_OK | AccessFlags::W_OK)
+        .map_err(|e| socket_error(io::Error::from(e), path))
+}
+
+#[cfg(not(unix))]
+fn check_read_write(_path: &str) -> Result<(), CrioError> {
+    Ok(())
+}
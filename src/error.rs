@@ -0,0 +1,249 @@
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::time::Duration;
+
+/// The error type returned by all `Cli` operations
+///
+/// New variants can be added in a minor release so a match on it needs a wildcard arm.
+///
+/// Serializes as `{"kind": "<variant>", "detail": ...}` for structured logs.
+/// The wrapped parse and io errors are kept as their messages and the raw output
+/// of `JsonParse` is dropped, so deserializing gives back an equivalent error rather than the same one.
+///
+/// ```compile_fail
+/// use libcrio::CrioError;
+/// fn is_timeout(e: &CrioError) -> bool {
+///     match e {
+///         CrioError::SpawnFailed(_)
+///         | CrioError::BinaryNotFound
+///         | CrioError::StderrNonEmpty(_)
+///         | CrioError::NonZeroExit { .. }
+///         | CrioError::JsonParse(_, _)
+///         | CrioError::TomlParse(_)
+///         | CrioError::TomlSerialize(_)
+///         | CrioError::NotFound(_)
+///         | CrioError::AmbiguousId(_)
+///         | CrioError::UnsupportedOperation(_)
+///         | CrioError::InvalidArgument(_)
+///         | CrioError::Io(_)
+///         | CrioError::ExecNotFound(_)
+///         | CrioError::FieldMissing(_)
+///         | CrioError::SocketNotFound(_)
+///         | CrioError::SocketPermissionDenied(_) => false,
+///         CrioError::Timeout(_) => true,
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CrioError {
+    /// The crictl process could not be started.
+    /// Usually this means crictl is not on the configured `bin_path`.
+    SpawnFailed(String),
+    /// No executable crictl was found on the configured `bin_path`.
+    BinaryNotFound,
+    /// crictl exited successfully but wrote to stderr. The value is the stderr content.
+    StderrNonEmpty(String),
+    /// crictl exited with a non-zero code.
+    /// The code is -1 if crictl was terminated by a signal.
+    NonZeroExit { code: i32, stderr: String },
+    /// The crictl output could not be parsed as JSON.
+    /// The second field holds the raw output that failed to parse.
+    JsonParse(serde_json::Error, String),
+    /// A TOML config file could not be parsed into a `Cli`.
+    TomlParse(toml::de::Error),
+    /// A `Cli` could not be written as TOML.
+    TomlSerialize(toml::ser::Error),
+    /// The requested item was not present in the crictl output.
+    NotFound(String),
+    /// An id prefix matched more than one item. The value holds the full ids that matched.
+    AmbiguousId(Vec<String>),
+    /// The runtime doesn't support the requested operation. The value is the crictl stderr.
+    UnsupportedOperation(String),
+    /// An argument couldn't be turned into a crictl flag. The value describes the argument.
+    InvalidArgument(String),
+    /// Reading from or waiting on the crictl process failed.
+    Io(io::Error),
+    /// crictl didn't finish within the `command_timeout` and was killed.
+    Timeout(Duration),
+    /// The command run with exec isn't in the container image. The value is the command.
+    ExecNotFound(String),
+    /// The crictl output parsed but lacked a field that was asked for. The value is the path of the field.
+    FieldMissing(String),
+    /// There is nothing at the path of a runtime endpoint. The value is the path.
+    SocketNotFound(String),
+    /// The runtime endpoint can't be read and written by this process. The value is the path.
+    SocketPermissionDenied(String),
+}
+
+impl fmt::Display for CrioError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CrioError::SpawnFailed(s) => write!(f, "failed to execute crictl {}", s),
+            CrioError::BinaryNotFound => write!(f, "crictl not found on bin_path"),
+            CrioError::StderrNonEmpty(s) => {
+                write!(f, "stderr not empty - failed to execute crictl {}", s)
+            }
+            CrioError::NonZeroExit { code, stderr } => {
+                write!(f, "crictl exited with code {} {}", code, stderr)
+            }
+            CrioError::JsonParse(e, _) => write!(f, "failed to parse crictl output {}", e),
+            CrioError::TomlParse(e) => write!(f, "failed to parse toml config {}", e),
+            CrioError::TomlSerialize(e) => write!(f, "failed to write toml config {}", e),
+            CrioError::NotFound(s) => write!(f, "not found {}", s),
+            CrioError::AmbiguousId(ids) => write!(f, "ambiguous id matches {}", ids.join(", ")),
+            CrioError::UnsupportedOperation(s) => write!(f, "unsupported operation {}", s),
+            CrioError::InvalidArgument(s) => write!(f, "invalid argument {}", s),
+            CrioError::Io(e) => write!(f, "failed to read crictl output {}", e),
+            CrioError::Timeout(d) => write!(f, "crictl timed out after {:?}", d),
+            CrioError::ExecNotFound(s) => write!(f, "command not found in container {}", s),
+            CrioError::FieldMissing(s) => write!(f, "field missing from crictl output {}", s),
+            CrioError::SocketNotFound(s) => write!(f, "runtime socket not found {}", s),
+            CrioError::SocketPermissionDenied(s) => {
+                write!(f, "permission denied on runtime socket {}", s)
+            }
+        }
+    }
+}
+
+/// The serialized form of `CrioError`
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "kind", content = "detail")]
+enum CrioErrorRepr {
+    SpawnFailed(String),
+    BinaryNotFound,
+    StderrNonEmpty(String),
+    NonZeroExit { code: i32, stderr: String },
+    JsonParse(String),
+    TomlParse(String),
+    TomlSerialize(String),
+    NotFound(String),
+    AmbiguousId(Vec<String>),
+    UnsupportedOperation(String),
+    InvalidArgument(String),
+    Io(String),
+    Timeout(Duration),
+    ExecNotFound(String),
+    FieldMissing(String),
+    SocketNotFound(String),
+    SocketPermissionDenied(String),
+}
+
+impl CrioError {
+    /// Returns an equivalent error, the same one that would come back from a serialize round trip
+    pub(crate) fn duplicate(&self) -> CrioError {
+        match self {
+            // Unlike a round trip the raw output is still there to keep
+            CrioError::JsonParse(e, raw) => {
+                CrioError::JsonParse(serde::de::Error::custom(e), raw.clone())
+            }
+            _ => CrioError::from_repr(self.to_repr()),
+        }
+    }
+
+    fn to_repr(&self) -> CrioErrorRepr {
+        match self {
+            CrioError::SpawnFailed(s) => CrioErrorRepr::SpawnFailed(s.clone()),
+            CrioError::BinaryNotFound => CrioErrorRepr::BinaryNotFound,
+            CrioError::StderrNonEmpty(s) => CrioErrorRepr::StderrNonEmpty(s.clone()),
+            CrioError::NonZeroExit { code, stderr } => CrioErrorRepr::NonZeroExit {
+                code: *code,
+                stderr: stderr.clone(),
+            },
+            CrioError::JsonParse(e, _) => CrioErrorRepr::JsonParse(e.to_string()),
+            CrioError::TomlParse(e) => CrioErrorRepr::TomlParse(e.to_string()),
+            CrioError::TomlSerialize(e) => CrioErrorRepr::TomlSerialize(e.to_string()),
+            CrioError::NotFound(s) => CrioErrorRepr::NotFound(s.clone()),
+            CrioError::AmbiguousId(ids) => CrioErrorRepr::AmbiguousId(ids.clone()),
+            CrioError::UnsupportedOperation(s) => CrioErrorRepr::UnsupportedOperation(s.clone()),
+            CrioError::InvalidArgument(s) => CrioErrorRepr::InvalidArgument(s.clone()),
+            CrioError::Io(e) => CrioErrorRepr::Io(e.to_string()),
+            CrioError::Timeout(d) => CrioErrorRepr::Timeout(*d),
+            CrioError::ExecNotFound(s) => CrioErrorRepr::ExecNotFound(s.clone()),
+            CrioError::FieldMissing(s) => CrioErrorRepr::FieldMissing(s.clone()),
+            CrioError::SocketNotFound(s) => CrioErrorRepr::SocketNotFound(s.clone()),
+            CrioError::SocketPermissionDenied(s) => {
+                CrioErrorRepr::SocketPermissionDenied(s.clone())
+            }
+        }
+    }
+
+    fn from_repr(repr: CrioErrorRepr) -> CrioError {
+        match repr {
+            CrioErrorRepr::SpawnFailed(s) => CrioError::SpawnFailed(s),
+            CrioErrorRepr::BinaryNotFound => CrioError::BinaryNotFound,
+            CrioErrorRepr::StderrNonEmpty(s) => CrioError::StderrNonEmpty(s),
+            CrioErrorRepr::NonZeroExit { code, stderr } => CrioError::NonZeroExit { code, stderr },
+            CrioErrorRepr::JsonParse(s) => {
+                CrioError::JsonParse(serde::de::Error::custom(s), String::new())
+            }
+            CrioErrorRepr::TomlParse(s) => CrioError::TomlParse(serde::de::Error::custom(s)),
+            CrioErrorRepr::TomlSerialize(s) => {
+                CrioError::TomlSerialize(serde::ser::Error::custom(s))
+            }
+            CrioErrorRepr::NotFound(s) => CrioError::NotFound(s),
+            CrioErrorRepr::AmbiguousId(ids) => CrioError::AmbiguousId(ids),
+            CrioErrorRepr::UnsupportedOperation(s) => CrioError::UnsupportedOperation(s),
+            CrioErrorRepr::InvalidArgument(s) => CrioError::InvalidArgument(s),
+            CrioErrorRepr::Io(s) => CrioError::Io(io::Error::other(s)),
+            CrioErrorRepr::Timeout(d) => CrioError::Timeout(d),
+            CrioErrorRepr::ExecNotFound(s) => CrioError::ExecNotFound(s),
+            CrioErrorRepr::FieldMissing(s) => CrioError::FieldMissing(s),
+            CrioErrorRepr::SocketNotFound(s) => CrioError::SocketNotFound(s),
+            CrioErrorRepr::SocketPermissionDenied(s) => CrioError::SocketPermissionDenied(s),
+        }
+    }
+}
+
+impl Serialize for CrioError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_repr().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for CrioError {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(CrioError::from_repr(CrioErrorRepr::deserialize(
+            deserializer,
+        )?))
+    }
+}
+
+impl Error for CrioError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CrioError::JsonParse(e, _) => Some(e),
+            CrioError::TomlParse(e) => Some(e),
+            CrioError::TomlSerialize(e) => Some(e),
+            CrioError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for CrioError {
+    fn from(e: io::Error) -> CrioError {
+        CrioError::Io(e)
+    }
+}
+
+/// The error returned when a string isn't one of the known `ImageCommand`s
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageCommandParseError {
+    /// The string that failed to parse.
+    pub input: String,
+}
+
+impl fmt::Display for ImageCommandParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "unknown image command '{}', expected 'img', 'images' or 'ctr'",
+            self.input
+        )
+    }
+}
+
+impl Error for ImageCommandParseError {}
@@ -0,0 +1,45 @@
+use serde::Serialize;
+
+/// The captured output of a command run inside a container with `Cli::exec`
+#[derive(Debug, Serialize, PartialEq, Eq, Clone)]
+pub struct ExecResult {
+    pub stdout: String,
+    pub stderr: String,
+    /// The exit code of the command.
+    /// Set to -1 if the process was terminated by a signal.
+    pub exit_code: i32,
+}
+
+/// A process running in a container as returned by `Cli::top`
+#[derive(Debug, Serialize, PartialEq, Eq, Clone)]
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub ppid: u32,
+    pub user: String,
+    /// The executable name, without its arguments
+    pub command: String,
+}
+
+impl ProcessInfo {
+    /// Parses the output of `ps -eo pid,ppid,user,comm` skipping the header
+    ///
+    /// Lines that don't start with two numbers are skipped.
+    pub(crate) fn parse_ps(output: &str) -> Vec<ProcessInfo> {
+        output
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split_whitespace();
+                let pid = fields.next()?.parse().ok()?;
+                let ppid = fields.next()?.parse().ok()?;
+                let user = fields.next()?.to_string();
+                let command = fields.collect::<Vec<_>>().join(" ");
+                Some(ProcessInfo {
+                    pid,
+                    ppid,
+                    user,
+                    command,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,246 @@
+use crate::{redact_args, run_command_text, run_program_output, spawn_program, CrioError};
+use std::collections::HashMap;
+use std::ffi::{OsStr, OsString};
+use std::process::{Child, Output};
+use std::time::Duration;
+
+/// Runs crictl for a `Cli`
+///
+/// `Cli` sends every crictl and ctr call through its executor, so swapping `RealCrictlExecutor`
+/// for `MockCrictlExecutor` lets them be tested without a binary.
+/// `execute` serves the calls that return crictl's output in one piece, `execute_output` the ones
+/// that read the exit code themselves such as exec, and `spawn` the ones that stream the output.
+/// Only `execute` has to be implemented, the other two fail with `CrioError::SpawnFailed` by default
+/// rather than starting a process.
+///
+/// The executor is shared between threads by `Cli::list_all` so it must be `Send` and `Sync`.
+pub trait CrictlExecutor: Send + Sync {
+    /// Runs crictl with `args` and returns its stdout
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The arguments passed to crictl, including any global flags
+    ///
+    /// * `bin_path` - The `bin_path` of the `Cli`, used as PATH to find crictl
+    ///
+    /// * `options` - The per call settings taken from the `Cli`
+    fn execute(
+        &self,
+        args: &[OsString],
+        bin_path: &str,
+        options: &CommandOptions,
+    ) -> Result<String, CrioError>;
+
+    /// Runs `program` with `args` and returns its exit status, stdout and stderr whatever the exit code
+    ///
+    /// # Arguments
+    ///
+    /// * `program` - "crictl", or "ctr" when the `image_command` is `ImageCommand::Ctr`
+    ///
+    /// * `args` - The arguments passed to the program, including any global flags
+    ///
+    /// * `bin_path` - The `bin_path` of the `Cli`, used as PATH to find the program
+    ///
+    /// * `options` - The per call settings taken from the `Cli`
+    fn execute_output(
+        &self,
+        program: &str,
+        args: &[OsString],
+        bin_path: &str,
+        options: &CommandOptions,
+    ) -> Result<Output, CrioError> {
+        let _ = (bin_path, options);
+        Err(not_supported(program, args))
+    }
+
+    /// Starts crictl with `args` and returns the running process for the calls that stream its output
+    ///
+    /// stdout and stderr are piped, or with `interactive` all of stdin, stdout and stderr
+    /// are inherited from the calling process. `options.timeout` is left to the caller.
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - The arguments passed to crictl, including any global flags
+    ///
+    /// * `bin_path` - The `bin_path` of the `Cli`, used as PATH to find crictl
+    ///
+    /// * `options` - The per call settings taken from the `Cli`
+    ///
+    /// * `interactive` - Attach crictl to the terminal instead of piping its output
+    fn spawn(
+        &self,
+        args: &[OsString],
+        bin_path: &str,
+        options: &CommandOptions,
+        interactive: bool,
+    ) -> Result<Child, CrioError> {
+        let _ = (bin_path, options, interactive);
+        Err(not_supported("crictl", args))
+    }
+}
+
+fn not_supported(program: &str, args: &[OsString]) -> CrioError {
+    CrioError::SpawnFailed(format!(
+        "{} {:?} can't be run by this executor",
+        program,
+        redact_args(args)
+    ))
+}
+
+/// The settings of a `Cli` that apply to each crictl call
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct CommandOptions {
+    /// Kill crictl if it hasn't finished within this time
+    pub timeout: Option<Duration>,
+    /// Passed to crictl as its environment along with PATH
+    pub extra_env: HashMap<String, String>,
+    /// Log the stderr of a successful call rather than returning `StderrNonEmpty`
+    pub warn_on_stderr: bool,
+}
+
+/// Runs the crictl binary found on the bin_path. The executor of `Cli::default()`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RealCrictlExecutor;
+
+impl CrictlExecutor for RealCrictlExecutor {
+    fn execute(
+        &self,
+        args: &[OsString],
+        bin_path: &str,
+        options: &CommandOptions,
+    ) -> Result<String, CrioError> {
+        run_command_text(args, bin_path, options)
+    }
+
+    fn execute_output(
+        &self,
+        program: &str,
+        args: &[OsString],
+        bin_path: &str,
+        options: &CommandOptions,
+    ) -> Result<Output, CrioError> {
+        run_program_output(program, args, bin_path, options)
+    }
+
+    fn spawn(
+        &self,
+        args: &[OsString],
+        bin_path: &str,
+        options: &CommandOptions,
+        interactive: bool,
+    ) -> Result<Child, CrioError> {
+        spawn_program("crictl", args, bin_path, options, interactive)
+    }
+}
+
+/// Returns canned output for each set of crictl arguments
+///
+/// The arguments are matched exactly, including the global flags the `Cli` adds.
+/// Arguments without a response give `CrioError::SpawnFailed` as if crictl couldn't be run.
+/// `respond` answers `execute` and `respond_output` answers `execute_output`, e.g. for exec.
+/// Streaming calls such as `follow_logs` can't be mocked and fail with `CrioError::SpawnFailed`.
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::{Cli, MockCrictlExecutor};
+/// let executor = MockCrictlExecutor::new()
+///     .respond(["--version"], Ok("crictl version v1.29.0\n".to_string()));
+/// let cli = Cli::default().with_executor(executor);
+/// assert_eq!(cli.detect_crictl_version().unwrap().to_string(), "1.29.0");
+/// assert!(cli.pods().is_err());
+/// ```
+#[derive(Debug, Default)]
+pub struct MockCrictlExecutor {
+    pub responses: HashMap<Vec<OsString>, Result<String, CrioError>>,
+    pub outputs: HashMap<Vec<OsString>, Result<Output, CrioError>>,
+}
+
+impl MockCrictlExecutor {
+    /// Returns an executor with no responses
+    pub fn new() -> MockCrictlExecutor {
+        MockCrictlExecutor::default()
+    }
+
+    /// Adds the result returned when crictl is run with `args`, replacing any earlier one
+    pub fn respond(
+        mut self,
+        args: impl IntoIterator<Item = impl AsRef<OsStr>>,
+        result: Result<String, CrioError>,
+    ) -> Self {
+        let args = args
+            .into_iter()
+            .map(|arg| arg.as_ref().to_os_string())
+            .collect();
+        self.responses.insert(args, result);
+        self
+    }
+
+    /// Adds the exit status, stdout and stderr returned when crictl or ctr is run with `args`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libcrio::{Cli, MockCrictlExecutor};
+    /// use std::os::unix::process::ExitStatusExt;
+    /// use std::process::{ExitStatus, Output};
+    /// let executor = MockCrictlExecutor::new().respond_output(
+    ///     ["exec", "7653", "--", "cat", "/missing"],
+    ///     Ok(Output {
+    ///         status: ExitStatus::from_raw(1 << 8),
+    ///         stdout: vec![],
+    ///         stderr: b"cat: /missing: No such file or directory\n".to_vec(),
+    ///     }),
+    /// );
+    /// let cli = Cli::default().with_executor(executor);
+    /// assert_eq!(cli.exec("7653", &["cat", "/missing"]).unwrap().exit_code, 1);
+    /// ```
+    pub fn respond_output(
+        mut self,
+        args: impl IntoIterator<Item = impl AsRef<OsStr>>,
+        result: Result<Output, CrioError>,
+    ) -> Self {
+        let args = args
+            .into_iter()
+            .map(|arg| arg.as_ref().to_os_string())
+            .collect();
+        self.outputs.insert(args, result);
+        self
+    }
+}
+
+impl CrictlExecutor for MockCrictlExecutor {
+    fn execute(
+        &self,
+        args: &[OsString],
+        _bin_path: &str,
+        _options: &CommandOptions,
+    ) -> Result<String, CrioError> {
+        match self.responses.get(args) {
+            Some(Ok(output)) => Ok(output.clone()),
+            Some(Err(e)) => Err(e.duplicate()),
+            None => Err(CrioError::SpawnFailed(format!(
+                "no mock response for {:?}",
+                args
+            ))),
+        }
+    }
+
+    fn execute_output(
+        &self,
+        program: &str,
+        args: &[OsString],
+        _bin_path: &str,
+        _options: &CommandOptions,
+    ) -> Result<Output, CrioError> {
+        match self.outputs.get(args) {
+            Some(Ok(output)) => Ok(output.clone()),
+            Some(Err(e)) => Err(e.duplicate()),
+            None => Err(CrioError::SpawnFailed(format!(
+                "no mock output for {} {:?}",
+                program, args
+            ))),
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{
+    Cli, Container, CrictlExecutor, CrioError, ExecResult, InspectContainerResult,
+    InspectPodResult, LogOptions, Pod, RealCrictlExecutor,
+};
+use std::ops::Deref;
+
+/// A pod returned by `Cli::pod` that keeps the `Cli` so calls can be chained without repeating the id
+///
+/// Derefs to the `Pod` so its fields can be read directly.
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::Cli;
+/// let bin_path = format!("{}/mock/iks", env!("CARGO_MANIFEST_DIR"));
+/// let cli = Cli {
+///     bin_path,
+///     ..Default::default()
+/// };
+/// let pod = cli.pod("tests").unwrap();
+/// println!("{}", pod.metadata);
+/// let containers = pod.containers().unwrap();
+/// ```
+#[derive(Debug, Clone)]
+pub struct PodHandle<'a, E = RealCrictlExecutor> {
+    cli: &'a Cli<E>,
+    pod: Pod,
+}
+
+impl<'a, E: CrictlExecutor> PodHandle<'a, E> {
+    /// Returns a handle to run `cli` commands against `pod`
+    pub fn new(cli: &'a Cli<E>, pod: Pod) -> PodHandle<'a, E> {
+        PodHandle { cli, pod }
+    }
+
+    /// Returns the `Pod` dropping the `Cli`
+    pub fn into_pod(self) -> Pod {
+        self.pod
+    }
+
+    /// The same as `Cli::pod_containers` for this pod
+    pub fn containers(&self) -> Result<Vec<Container>, CrioError> {
+        self.cli.pod_containers(&self.pod.id)
+    }
+
+    /// Returns a `ContainerHandle` for each of the running containers in this pod
+    pub fn container_handles(&self) -> Result<Vec<ContainerHandle<'a, E>>, CrioError> {
+        let cli = self.cli;
+        Ok(self
+            .containers()?
+            .into_iter()
+            .map(|c| ContainerHandle::new(cli, c))
+            .collect())
+    }
+
+    /// The same as `Cli::inspect_pod_typed` for this pod
+    pub fn inspect(&self) -> Result<InspectPodResult, CrioError> {
+        self.cli.inspect_pod_typed(&self.pod.id)
+    }
+
+    /// The same as `Cli::stopp` for this pod
+    pub fn stop(&self) -> Result<(), CrioError> {
+        self.cli.stopp(&self.pod.id)
+    }
+}
+
+impl<E> Deref for PodHandle<'_, E> {
+    type Target = Pod;
+
+    fn deref(&self) -> &Pod {
+        &self.pod
+    }
+}
+
+/// A container that keeps the `Cli` so calls can be chained without repeating the id
+///
+/// Derefs to the `Container` so its fields can be read directly.
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::Cli;
+/// let bin_path = format!("{}/mock/iks", env!("CARGO_MANIFEST_DIR"));
+/// let cli = Cli {
+///     bin_path,
+///     ..Default::default()
+/// };
+/// for container in cli.pod("tests").unwrap().container_handles().unwrap() {
+///     let logs = container.logs().unwrap();
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ContainerHandle<'a, E = RealCrictlExecutor> {
+    cli: &'a Cli<E>,
+    container: Container,
+}
+
+impl<'a, E: CrictlExecutor> ContainerHandle<'a, E> {
+    /// Returns a handle to run `cli` commands against `container`
+    pub fn new(cli: &'a Cli<E>, container: Container) -> ContainerHandle<'a, E> {
+        ContainerHandle { cli, container }
+    }
+
+    /// Returns the `Container` dropping the `Cli`
+    pub fn into_container(self) -> Container {
+        self.container
+    }
+
+    /// The same as `Cli::inspect_container_typed` for this container
+    pub fn inspect(&self) -> Result<InspectContainerResult, CrioError> {
+        self.cli.inspect_container_typed(&self.container.id)
+    }
+
+    /// Returns the whole log of this container, the same as `Cli::logs_with_options` with no options set
+    pub fn logs(&self) -> Result<String, CrioError> {
+        self.cli
+            .logs_with_options(&self.container.id, &LogOptions::default())
+    }
+
+    /// The same as `Cli::stop` for this container
+    pub fn stop(&self) -> Result<(), CrioError> {
+        self.cli.stop(&self.container.id)
+    }
+
+    /// The same as `Cli::exec` for this container
+    pub fn exec(&self, command: &[&str]) -> Result<ExecResult, CrioError> {
+        self.cli.exec(&self.container.id, command)
+    }
+}
+
+impl<E> Deref for ContainerHandle<'_, E> {
+    type Target = Container;
+
+    fn deref(&self) -> &Container {
+        &self.container
+    }
+}
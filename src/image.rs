@@ -0,0 +1,119 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// An image as returned by `crictl img`
+///
+/// Equality and hashing only use `id` so two values for the same image are equal
+/// even if their other fields differ. This lets them be deduplicated in a `HashSet`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Image {
+    pub id: String,
+    #[serde(default)]
+    pub repo_tags: Vec<String>,
+    #[serde(default)]
+    pub repo_digests: Vec<String>,
+    /// The size in bytes
+    #[serde(default, deserialize_with = "crate::de::from_str_or_num")]
+    pub size: u64,
+    /// Any fields not mapped above
+    #[serde(flatten)]
+    pub raw: Value,
+}
+
+impl Image {
+    /// True if the runtime reports the image as still being pulled in its `status` field
+    pub(crate) fn is_pulling(&self) -> bool {
+        self.raw["status"]
+            .as_str()
+            .is_some_and(|s| s.eq_ignore_ascii_case("pulling"))
+    }
+}
+
+/// Whether an image is on the node as returned by `Cli::image_pull_status`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ImagePullStatus {
+    /// The image has been pulled
+    Present(Image),
+    /// The runtime lists the image but is still pulling it
+    Pulling,
+    /// The runtime doesn't list the image
+    NotPresent,
+}
+
+/// The outcome of `Cli::pull_if_newer`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PullResult {
+    /// False when the image on the node already had the digest asked for
+    pub pulled: bool,
+    /// The image on the node after the call
+    pub image: Image,
+}
+
+impl PartialEq for Image {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Image {}
+
+impl Hash for Image {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+/// The image filesystem usage returned by `Cli::imagefsinfo`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ImageFsInfo {
+    #[serde(default)]
+    pub image_filesystems: Vec<FsInfo>,
+}
+
+/// The usage of a single filesystem
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct FsInfo {
+    /// Nanoseconds since the unix epoch
+    #[serde(default, deserialize_with = "crate::de::from_str_or_num")]
+    pub timestamp: i64,
+    /// The mountpoint of the filesystem
+    #[serde(default, deserialize_with = "fs_id")]
+    pub fs_id: String,
+    #[serde(default, deserialize_with = "crate::de::from_uint64_value")]
+    pub used_bytes: u64,
+    #[serde(default, deserialize_with = "crate::de::from_uint64_value")]
+    pub inodes_used: u64,
+}
+
+#[derive(Deserialize)]
+struct FilesystemIdentifier {
+    #[serde(default)]
+    mountpoint: String,
+}
+
+fn fs_id<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    Ok(Option::<FilesystemIdentifier>::deserialize(deserializer)?
+        .map(|id| id.mountpoint)
+        .unwrap_or_default())
+}
+
+/// Credentials for pulling an image from a private registry with `Cli::pull_with_auth`
+#[derive(Clone, PartialEq, Eq)]
+pub struct PullAuth {
+    pub username: String,
+    pub password: String,
+}
+
+impl fmt::Debug for PullAuth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PullAuth")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .finish()
+    }
+}
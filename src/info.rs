@@ -0,0 +1,50 @@
+use serde::Serialize;
+use serde_json::Value;
+
+/// Commonly used fields from `crictl info`
+///
+/// containerd and CRI-O report their configuration differently so
+/// each field is `None` when the runtime doesn't report it.
+#[derive(Debug, Serialize, PartialEq, Eq, Clone, Default)]
+pub struct RuntimeInfo {
+    /// The storage driver or snapshotter e.g. "overlay" or "overlayfs"
+    pub storage_driver: Option<String>,
+    /// The root directory for image and container storage
+    pub storage_root: Option<String>,
+    /// "systemd" or "cgroupfs"
+    pub cgroup_driver: Option<String>,
+    /// The name of the default low level runtime e.g. "runc"
+    pub default_runtime: Option<String>,
+}
+
+impl RuntimeInfo {
+    pub(crate) fn from_value(info: &Value) -> RuntimeInfo {
+        let config = &info["config"];
+        if config.get("containerd").is_some() {
+            let containerd = &config["containerd"];
+            let default_runtime = string(&containerd["defaultRuntimeName"]);
+            let cgroup_driver = default_runtime.as_ref().and_then(|name| {
+                containerd["runtimes"][name]["options"]["SystemdCgroup"]
+                    .as_bool()
+                    .map(|systemd| if systemd { "systemd" } else { "cgroupfs" }.to_string())
+            });
+            RuntimeInfo {
+                storage_driver: string(&containerd["snapshotter"]),
+                storage_root: string(&config["containerdRootDir"]),
+                cgroup_driver,
+                default_runtime,
+            }
+        } else {
+            RuntimeInfo {
+                storage_driver: string(&config["storage_driver"]),
+                storage_root: string(&config["root"]),
+                cgroup_driver: string(&config["runtime"]["cgroup_manager"]),
+                default_runtime: string(&config["runtime"]["default_runtime"]),
+            }
+        }
+    }
+}
+
+fn string(v: &Value) -> Option<String> {
+    v.as_str().map(|s| s.to_string())
+}
@@ -0,0 +1,124 @@
+use crate::{ContainerMetadata, ContainerState, ImageSpec, PodMetadata, PodState};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The output of `crictl inspectp` as returned by `Cli::inspect_pod_typed`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InspectPodResult {
+    pub status: PodSandboxStatus,
+    #[serde(default)]
+    pub info: PodSandboxInfo,
+}
+
+/// The `status` of a pod sandbox as reported by the runtime
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PodSandboxStatus {
+    pub id: String,
+    #[serde(default)]
+    pub metadata: PodMetadata,
+    pub state: PodState,
+    /// An RFC3339 time, unlike the nanoseconds of `Pod::created_at`
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+    #[serde(default)]
+    pub annotations: HashMap<String, String>,
+    /// Any fields not mapped above
+    #[serde(flatten)]
+    pub raw: Value,
+}
+
+/// The runtime specific `info` of a pod sandbox
+///
+/// Only `pid` is reported by both containerd and CRI-O, the other fields are empty when missing.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct PodSandboxInfo {
+    /// The pid of the pause process
+    #[serde(default)]
+    pub pid: u32,
+    /// e.g. "io.containerd.runc.v2"
+    #[serde(default)]
+    pub runtime_type: String,
+    /// The OCI runtime spec the sandbox was created with
+    #[serde(default)]
+    pub runtime_spec: Value,
+    /// Any fields not mapped above
+    #[serde(flatten)]
+    pub raw: Value,
+}
+
+/// A network interface of a pod sandbox as returned by `Cli::inspect_pod_network`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct NetworkInterface {
+    /// The name of the interface inside the pod, e.g. "eth0" or "net1" for one added by Multus
+    pub name: String,
+    /// The IPv4 and IPv6 addresses of the interface
+    pub ip_addresses: Vec<String>,
+}
+
+/// The output of `crictl inspect` as returned by `Cli::inspect_container_typed`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct InspectContainerResult {
+    pub status: ContainerStatus,
+    #[serde(default)]
+    pub info: ContainerInfo,
+}
+
+/// The `status` of a container as reported by the runtime
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ContainerStatus {
+    pub id: String,
+    #[serde(default)]
+    pub metadata: ContainerMetadata,
+    pub state: ContainerState,
+    /// An RFC3339 time, unlike the nanoseconds of `Container::created_at`
+    #[serde(default)]
+    pub created_at: String,
+    #[serde(default)]
+    pub started_at: String,
+    #[serde(default)]
+    pub finished_at: String,
+    #[serde(default)]
+    pub exit_code: i32,
+    #[serde(default)]
+    pub image: ImageSpec,
+    #[serde(default)]
+    pub image_ref: String,
+    #[serde(default)]
+    pub log_path: String,
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+    #[serde(default)]
+    pub annotations: HashMap<String, String>,
+    /// Any fields not mapped above
+    #[serde(flatten)]
+    pub raw: Value,
+}
+
+/// The runtime specific `info` of a container
+///
+/// Only `sandbox_id` and `pid` are reported by both containerd and CRI-O, the other fields are empty when missing.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct ContainerInfo {
+    /// The id of the pod sandbox the container runs in
+    #[serde(default, rename = "sandboxID")]
+    pub sandbox_id: String,
+    /// The pid of the container's init process
+    #[serde(default)]
+    pub pid: u32,
+    /// e.g. "io.containerd.runc.v2"
+    #[serde(default)]
+    pub runtime_type: String,
+    /// The OCI runtime spec the container was created with
+    #[serde(default)]
+    pub runtime_spec: Value,
+    /// Any fields not mapped above
+    #[serde(flatten)]
+    pub raw: Value,
+}
@@ -0,0 +1,176 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use std::time::Duration;
+
+/// The options for a `Cli::logs_with_options` query
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::LogOptions;
+/// let opts = LogOptions::builder().tail(100).timestamps(true).build();
+/// assert_eq!(opts.tail, Some(100));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LogOptions {
+    /// The number of lines to take from the end of the log.
+    pub tail: Option<u32>,
+    /// Only return logs newer than this relative duration.
+    pub since: Option<Duration>,
+    /// Only return logs after this time. Takes precedence over `since` when both are set.
+    pub since_time: Option<DateTime<Utc>>,
+    /// Prefix each line with its timestamp.
+    pub timestamps: bool,
+    /// Keep streaming the log. crictl won't exit so this should be paired with a `command_timeout`.
+    pub follow: bool,
+}
+
+impl LogOptions {
+    /// Returns a `LogOptionsBuilder` with no options set
+    pub fn builder() -> LogOptionsBuilder {
+        LogOptionsBuilder::default()
+    }
+
+    pub(crate) fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+        if let Some(tail) = self.tail {
+            args.push(format!("--tail={}", tail));
+        }
+        if let Some(since_time) = self.since_time {
+            args.push(format!(
+                "--since={}",
+                since_time.to_rfc3339_opts(SecondsFormat::Secs, true)
+            ));
+        } else if let Some(since) = self.since {
+            args.push(format!("--since={}", go_duration(since)));
+        }
+        if self.timestamps {
+            args.push("--timestamps".to_string());
+        }
+        if self.follow {
+            args.push("--follow".to_string());
+        }
+        args
+    }
+}
+
+/// Formats a duration the way crictl parses it, keeping milliseconds only when they're needed
+fn go_duration(d: Duration) -> String {
+    if d.subsec_millis() == 0 {
+        format!("{}s", d.as_secs())
+    } else {
+        format!("{}ms", d.as_millis())
+    }
+}
+
+/// A builder for `LogOptions`
+///
+/// Created with `LogOptions::builder()`.
+#[derive(Debug, Clone, Default)]
+pub struct LogOptionsBuilder {
+    opts: LogOptions,
+}
+
+impl LogOptionsBuilder {
+    /// Sets the number of lines to take from the end of the log
+    pub fn tail(mut self, tail: u32) -> Self {
+        self.opts.tail = Some(tail);
+        self
+    }
+
+    /// Only return logs newer than this relative duration
+    pub fn since(mut self, since: Duration) -> Self {
+        self.opts.since = Some(since);
+        self
+    }
+
+    /// Only return logs after this time
+    pub fn since_time(mut self, since_time: DateTime<Utc>) -> Self {
+        self.opts.since_time = Some(since_time);
+        self
+    }
+
+    /// Prefix each line with its timestamp
+    pub fn timestamps(mut self, timestamps: bool) -> Self {
+        self.opts.timestamps = timestamps;
+        self
+    }
+
+    /// Keep streaming the log
+    pub fn follow(mut self, follow: bool) -> Self {
+        self.opts.follow = follow;
+        self
+    }
+
+    /// Returns the configured `LogOptions`
+    pub fn build(self) -> LogOptions {
+        self.opts
+    }
+}
+
+/// The stream a `LogLine` was written to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogStream {
+    Stdout,
+    Stderr,
+}
+
+/// A single line of CRI formatted container log
+///
+/// CRI runtimes write each line as `<RFC3339Nano> <stream> <flags> <message>`
+/// where the flag is `P` for a partial line and `F` for a full one.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogLine {
+    /// When the line was written.
+    /// Set to the unix epoch if the line wasn't in the CRI format.
+    pub timestamp: DateTime<Utc>,
+    pub stream: LogStream,
+    /// The runtime split a long line and this is not the end of it.
+    pub partial: bool,
+    /// The log text. Holds the whole raw line if it wasn't in the CRI format.
+    pub message: String,
+}
+
+impl LogLine {
+    /// Parses a CRI formatted log line
+    ///
+    /// Lines that aren't in the CRI format are returned with the unix epoch as the
+    /// timestamp and the raw text as the message.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use libcrio::{LogLine, LogStream};
+    /// let line = LogLine::parse("2023-04-01T12:30:00.123456789Z stderr F oops");
+    /// assert_eq!(line.stream, LogStream::Stderr);
+    /// assert_eq!(line.message, "oops");
+    /// ```
+    pub fn parse(line: &str) -> LogLine {
+        LogLine::parse_cri(line).unwrap_or_else(|| LogLine {
+            timestamp: DateTime::UNIX_EPOCH,
+            stream: LogStream::Stdout,
+            partial: false,
+            message: line.to_string(),
+        })
+    }
+
+    fn parse_cri(line: &str) -> Option<LogLine> {
+        let mut parts = line.splitn(4, ' ');
+        let timestamp = DateTime::parse_from_rfc3339(parts.next()?).ok()?;
+        let stream = match parts.next()? {
+            "stdout" => LogStream::Stdout,
+            "stderr" => LogStream::Stderr,
+            _ => return None,
+        };
+        let partial = match parts.next()? {
+            "P" => true,
+            "F" => false,
+            _ => return None,
+        };
+        Some(LogLine {
+            timestamp: timestamp.with_timezone(&Utc),
+            stream,
+            partial,
+            message: parts.next().unwrap_or_default().to_string(),
+        })
+    }
+}
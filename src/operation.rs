@@ -0,0 +1,62 @@
+use crate::{ContainerState, LogOptions};
+
+/// A `Cli` call whose crictl arguments can be looked at with `Cli::crictl_args`
+///
+/// Each variant holds the arguments of the `Cli` method it's named after.
+/// New variants can be added in a minor release so a match on it needs a wildcard arm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum CrictlOperation {
+    /// `Cli::pod` and `Cli::pods_by_name` with the hostname
+    Pod(String),
+    /// `Cli::pods`
+    Pods,
+    /// `Cli::pods_by_namespace` with the Kubernetes namespace
+    PodsByNamespace(String),
+    /// `Cli::pods_by_label` with the selector
+    PodsByLabel(String),
+    /// `Cli::inspect_pod` with the pod id
+    InspectPod(String),
+    /// `Cli::stopp` with the pod id
+    StopPod(String),
+    /// `Cli::rmp` with the pod id
+    RemovePod(String),
+    /// `Cli::pod_containers` with the pod id
+    PodContainers(String),
+    /// `Cli::pod_containers_all` with the pod id
+    PodContainersAll(String),
+    /// `Cli::containers_all`
+    ContainersAll,
+    /// `Cli::containers_by_state` with the state
+    ContainersByState(ContainerState),
+    /// `Cli::inspect_container` with the container id
+    InspectContainer(String),
+    /// `Cli::start` with the container id
+    Start(String),
+    /// `Cli::stop` with the container id
+    Stop(String),
+    /// `Cli::rm` with the container id
+    Remove(String),
+    /// `Cli::stats` with the container id
+    Stats(String),
+    /// `Cli::all_stats`
+    AllStats,
+    /// `Cli::statsp` with the pod id
+    PodStats(String),
+    /// `Cli::images` and `Cli::image`
+    Images,
+    /// `Cli::rmi` with the image reference
+    RemoveImage(String),
+    /// `Cli::pull` with the image reference
+    Pull(String),
+    /// `Cli::logs_with_options` with the container id and options
+    Logs(String, LogOptions),
+    /// `Cli::exec` with the container id and command
+    Exec(String, Vec<String>),
+    /// `Cli::imagefsinfo`
+    ImageFsInfo,
+    /// The `crictl version` call made by `Cli::version`
+    Version,
+    /// `Cli::info`
+    Info,
+}
@@ -0,0 +1,258 @@
+use crate::{debug, Container, CrioError, Image};
+use chrono::{DateTime, SecondsFormat};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::Deref;
+use std::str::FromStr;
+use std::sync::mpsc::{Receiver, Sender};
+use std::thread::JoinHandle;
+
+/// A pod sandbox as returned by `crictl pods`
+///
+/// Equality and hashing only use `id` so two values for the same pod are equal
+/// even if their other fields differ. This lets them be deduplicated in a `HashSet`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Pod {
+    pub id: String,
+    #[serde(default)]
+    pub metadata: PodMetadata,
+    pub state: PodState,
+    /// Nanoseconds since the unix epoch
+    #[serde(default, deserialize_with = "crate::de::from_str_or_num")]
+    pub created_at: i64,
+    #[serde(default)]
+    pub labels: HashMap<String, String>,
+    #[serde(default)]
+    pub annotations: HashMap<String, String>,
+    /// Any fields not mapped above
+    #[serde(flatten)]
+    pub raw: Value,
+}
+
+impl Pod {
+    /// Returns true if the pod sandbox is in the `Ready` state
+    pub fn is_ready(&self) -> bool {
+        self.state == PodState::Ready
+    }
+
+    /// Returns the kubernetes name of the pod, or "" when crictl didn't report one
+    pub fn name(&self) -> &str {
+        if self.metadata.name.is_empty() {
+            debug!("pod {} has no name in its metadata", self.id);
+        }
+        &self.metadata.name
+    }
+
+    /// Returns the kubernetes namespace of the pod, or "" when crictl didn't report one
+    pub fn namespace(&self) -> &str {
+        if self.metadata.namespace.is_empty() {
+            debug!("pod {} has no namespace in its metadata", self.id);
+        }
+        &self.metadata.namespace
+    }
+}
+
+impl PartialEq for Pod {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for Pod {}
+
+impl Hash for Pod {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+/// The metadata of a pod sandbox
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct PodMetadata {
+    #[serde(default)]
+    pub name: String,
+    #[serde(default)]
+    pub namespace: String,
+    #[serde(default)]
+    pub uid: String,
+    #[serde(default)]
+    pub attempt: u32,
+}
+
+impl fmt::Display for PodMetadata {
+    /// Formats the pod as `namespace/name` or just `name` when there's no namespace
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.namespace.is_empty() {
+            write!(f, "{}", self.name)
+        } else {
+            write!(f, "{}/{}", self.namespace, self.name)
+        }
+    }
+}
+
+/// The state of a pod sandbox
+///
+/// Parsed from the crictl strings e.g. "SANDBOX_READY".
+/// A state this version doesn't know about becomes `Unknown` rather than an error.
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PodState {
+    #[serde(rename = "SANDBOX_READY")]
+    Ready,
+    #[serde(rename = "SANDBOX_NOTREADY")]
+    NotReady,
+    Unknown,
+}
+
+impl FromStr for PodState {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "SANDBOX_READY" => PodState::Ready,
+            "SANDBOX_NOTREADY" => PodState::NotReady,
+            _ => {
+                debug!("unrecognised pod state {}", s);
+                PodState::Unknown
+            }
+        })
+    }
+}
+
+impl<'de> Deserialize<'de> for PodState {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        Ok(s.parse().unwrap_or(PodState::Unknown))
+    }
+}
+
+/// A pod together with its containers and their images as returned by `Cli::pod_full_info`
+#[derive(Debug)]
+pub struct PodFullInfo {
+    pub pod: Pod,
+    pub containers: Vec<Container>,
+    /// The images used by the containers. Each image is only listed once.
+    pub images: Vec<Image>,
+    /// The image references that couldn't be looked up and why
+    pub image_errors: Vec<(String, CrioError)>,
+}
+
+/// The outcome of `Cli::drain_pod`
+#[derive(Debug, Default)]
+pub struct DrainResult {
+    /// The ids of the containers that were stopped
+    pub succeeded: Vec<String>,
+    /// The ids of the containers that couldn't be stopped and why
+    pub failed: Vec<(String, CrioError)>,
+}
+
+/// A change to the pods on the node as sent by `Cli::watch_pods`
+// The diffs are sent one at a time so boxing the pods of StateChanged would only add an allocation
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PodDiff {
+    /// A pod that wasn't listed by the previous poll
+    Added(Pod),
+    /// A pod listed by the previous poll that has gone
+    Removed(Pod),
+    /// A pod whose state differs from the previous poll
+    StateChanged { old: Pod, new: Pod },
+}
+
+/// The diffs sent by `Cli::watch_pods`, read through `Deref` to the `Receiver`
+///
+/// Dropping it stops the polling thread without waiting for the interval to pass
+/// and blocks until a poll already running has finished.
+#[derive(Debug)]
+pub struct PodWatch {
+    pub(crate) diffs: Receiver<PodDiff>,
+    pub(crate) stop: Option<Sender<()>>,
+    pub(crate) thread: Option<JoinHandle<()>>,
+}
+
+impl Deref for PodWatch {
+    type Target = Receiver<PodDiff>;
+
+    fn deref(&self) -> &Receiver<PodDiff> {
+        &self.diffs
+    }
+}
+
+impl Drop for PodWatch {
+    fn drop(&mut self) {
+        // The thread waits on the other end between polls and stops once this is gone
+        drop(self.stop.take());
+        if let Some(thread) = self.thread.take() {
+            if thread.join().is_err() {
+                debug!("pod watch thread panicked");
+            }
+        }
+    }
+}
+
+/// Returns the changes from `old` to `new` matching the pods by id
+///
+/// Additions and state changes come in the order of `new`, followed by the removals in the order of `old`.
+pub(crate) fn diff_pods(old: &[Pod], new: &[Pod]) -> Vec<PodDiff> {
+    let old_by_id: HashMap<&str, &Pod> = old.iter().map(|p| (p.id.as_str(), p)).collect();
+    let new_by_id: HashMap<&str, &Pod> = new.iter().map(|p| (p.id.as_str(), p)).collect();
+    let mut diffs: Vec<PodDiff> = new
+        .iter()
+        .filter_map(|pod| match old_by_id.get(pod.id.as_str()) {
+            None => Some(PodDiff::Added(pod.clone())),
+            Some(old) if old.state != pod.state => Some(PodDiff::StateChanged {
+                old: (*old).clone(),
+                new: pod.clone(),
+            }),
+            Some(_) => None,
+        })
+        .collect();
+    diffs.extend(
+        old.iter()
+            .filter(|pod| !new_by_id.contains_key(pod.id.as_str()))
+            .map(|pod| PodDiff::Removed(pod.clone())),
+    );
+    diffs
+}
+
+/// Formats pods as a table with a column per field, each as wide as its widest value
+///
+/// Ids are shortened to 13 characters like `crictl pods` does.
+pub(crate) fn pods_table(pods: &[Pod]) -> String {
+    let header = ["ID", "NAME", "NAMESPACE", "STATE", "CREATED"];
+    let rows: Vec<[String; 5]> = pods
+        .iter()
+        .map(|p| {
+            [
+                p.id.chars().take(13).collect(),
+                p.metadata.name.clone(),
+                p.metadata.namespace.clone(),
+                format!("{:?}", p.state),
+                DateTime::from_timestamp_nanos(p.created_at)
+                    .to_rfc3339_opts(SecondsFormat::Secs, true),
+            ]
+        })
+        .collect();
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (width, value) in widths.iter_mut().zip(row) {
+            *width = (*width).max(value.len());
+        }
+    }
+    let mut table = String::new();
+    let lines = std::iter::once(header.map(String::from)).chain(rows);
+    for line in lines {
+        let cells: Vec<String> = line
+            .iter()
+            .zip(widths)
+            .map(|(value, width)| format!("{:<width$}", value, width = width))
+            .collect();
+        table.push_str(cells.join("   ").trim_end());
+        table.push('\n');
+    }
+    table
+}
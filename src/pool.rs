@@ -0,0 +1,95 @@
+use crate::{join, Cli, CrictlExecutor, CrioError, RealCrictlExecutor};
+use std::collections::HashMap;
+use std::thread;
+
+/// A `Cli` for each node of a cluster, queried together by `query_all`
+///
+/// Each `Cli` is set up to reach its own node, e.g. with a `runtime_endpoint`
+/// pointing at the node's runtime socket forwarded over SSH.
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::{Cli, CliPool};
+/// let bin_path = format!("{}/mock/iks", env!("CARGO_MANIFEST_DIR"));
+/// let mut pool = CliPool::new();
+/// pool.add_node("worker-1", Cli::builder().bin_path(bin_path).build().unwrap());
+/// let pods = pool.query_all(|cli| cli.pods());
+/// assert_eq!(pods["worker-1"].as_ref().unwrap().len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct CliPool<E = RealCrictlExecutor> {
+    clients: HashMap<String, Cli<E>>,
+}
+
+impl<E> Default for CliPool<E> {
+    fn default() -> CliPool<E> {
+        CliPool {
+            clients: HashMap::new(),
+        }
+    }
+}
+
+impl<E: CrictlExecutor> CliPool<E> {
+    /// Returns a pool without any nodes
+    pub fn new() -> CliPool<E> {
+        CliPool::default()
+    }
+
+    /// Adds the `Cli` for a node, returning the one it replaces if the name was already in the pool
+    pub fn add_node(&mut self, name: impl Into<String>, cli: Cli<E>) -> Option<Cli<E>> {
+        self.clients.insert(name.into(), cli)
+    }
+
+    /// Removes a node from the pool and returns its `Cli`
+    pub fn remove_node(&mut self, name: &str) -> Option<Cli<E>> {
+        self.clients.remove(name)
+    }
+
+    /// Returns the `Cli` of a node
+    pub fn node(&self, name: &str) -> Option<&Cli<E>> {
+        self.clients.get(name)
+    }
+
+    /// Returns the names of the nodes in the pool in no particular order
+    pub fn node_names(&self) -> impl Iterator<Item = &str> {
+        self.clients.keys().map(String::as_str)
+    }
+
+    /// Returns the number of nodes in the pool
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    /// Returns true if there are no nodes in the pool
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+
+    /// Calls `f` with the `Cli` of every node at the same time and returns the results by node name
+    ///
+    /// Each node gets its own thread so a slow node only delays the return of this call,
+    /// not the queries of the others. An error from one node is returned for that node alone.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The query to run, e.g. `|cli| cli.pods()`
+    pub fn query_all<F, T>(&self, f: F) -> HashMap<String, Result<T, CrioError>>
+    where
+        F: Fn(&Cli<E>) -> Result<T, CrioError> + Send + Sync,
+        T: Send,
+    {
+        let f = &f;
+        thread::scope(|s| {
+            let handles: Vec<_> = self
+                .clients
+                .iter()
+                .map(|(name, cli)| (name, s.spawn(move || f(cli))))
+                .collect();
+            handles
+                .into_iter()
+                .map(|(name, handle)| (name.clone(), join(handle)))
+                .collect()
+        })
+    }
+}
@@ -0,0 +1,99 @@
+/// The resource limits to change with `Cli::update`
+///
+/// Only the fields that are set are passed to crictl.
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::ResourceUpdateOptions;
+/// let opts = ResourceUpdateOptions::builder()
+///     .cpu_quota(50000)
+///     .memory(256 * 1024 * 1024)
+///     .build();
+/// assert_eq!(opts.cpu_period, None);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResourceUpdateOptions {
+    /// The CPU CFS period in microseconds
+    pub cpu_period: Option<u64>,
+    /// The CPU CFS quota in microseconds per period
+    pub cpu_quota: Option<i64>,
+    /// The relative CPU weight
+    pub cpu_shares: Option<u64>,
+    /// The memory limit in bytes
+    pub memory: Option<i64>,
+    /// The memory plus swap limit in bytes
+    pub memory_swap: Option<i64>,
+}
+
+impl ResourceUpdateOptions {
+    /// Returns a `ResourceUpdateOptionsBuilder` with no limits set
+    pub fn builder() -> ResourceUpdateOptionsBuilder {
+        ResourceUpdateOptionsBuilder::default()
+    }
+
+    pub(crate) fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+        if let Some(cpu_period) = self.cpu_period {
+            args.push(format!("--cpu-period={}", cpu_period));
+        }
+        if let Some(cpu_quota) = self.cpu_quota {
+            args.push(format!("--cpu-quota={}", cpu_quota));
+        }
+        if let Some(cpu_shares) = self.cpu_shares {
+            args.push(format!("--cpu-share={}", cpu_shares));
+        }
+        if let Some(memory) = self.memory {
+            args.push(format!("--memory={}", memory));
+        }
+        if let Some(memory_swap) = self.memory_swap {
+            args.push(format!("--memory-swap={}", memory_swap));
+        }
+        args
+    }
+}
+
+/// A builder for `ResourceUpdateOptions`
+///
+/// Created with `ResourceUpdateOptions::builder()`.
+#[derive(Debug, Clone, Default)]
+pub struct ResourceUpdateOptionsBuilder {
+    opts: ResourceUpdateOptions,
+}
+
+impl ResourceUpdateOptionsBuilder {
+    /// Sets the CPU CFS period in microseconds
+    pub fn cpu_period(mut self, cpu_period: u64) -> Self {
+        self.opts.cpu_period = Some(cpu_period);
+        self
+    }
+
+    /// Sets the CPU CFS quota in microseconds per period
+    pub fn cpu_quota(mut self, cpu_quota: i64) -> Self {
+        self.opts.cpu_quota = Some(cpu_quota);
+        self
+    }
+
+    /// Sets the relative CPU weight
+    pub fn cpu_shares(mut self, cpu_shares: u64) -> Self {
+        self.opts.cpu_shares = Some(cpu_shares);
+        self
+    }
+
+    /// Sets the memory limit in bytes
+    pub fn memory(mut self, memory: i64) -> Self {
+        self.opts.memory = Some(memory);
+        self
+    }
+
+    /// Sets the memory plus swap limit in bytes
+    pub fn memory_swap(mut self, memory_swap: i64) -> Self {
+        self.opts.memory_swap = Some(memory_swap);
+        self
+    }
+
+    /// Returns the configured `ResourceUpdateOptions`
+    pub fn build(self) -> ResourceUpdateOptions {
+        self.opts
+    }
+}
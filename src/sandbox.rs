@@ -0,0 +1,80 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+/// The config a pod sandbox was created with as returned by `Cli::pod_sandbox_config_typed`
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default)]
+pub struct SandboxConfig {
+    #[serde(default)]
+    pub hostname: String,
+    #[serde(default)]
+    pub log_directory: String,
+    #[serde(default)]
+    pub dns_config: DnsConfig,
+    #[serde(default)]
+    pub port_mappings: Vec<PortMapping>,
+    /// Any fields not mapped above
+    #[serde(flatten)]
+    pub raw: Value,
+}
+
+/// The DNS settings of a pod sandbox
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, Default)]
+pub struct DnsConfig {
+    #[serde(default)]
+    pub servers: Vec<String>,
+    #[serde(default)]
+    pub searches: Vec<String>,
+    #[serde(default)]
+    pub options: Vec<String>,
+}
+
+/// A port exposed on the host by a pod sandbox
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct PortMapping {
+    #[serde(default)]
+    pub protocol: Protocol,
+    #[serde(default)]
+    pub container_port: i32,
+    #[serde(default)]
+    pub host_port: i32,
+    #[serde(default)]
+    pub host_ip: String,
+}
+
+/// The protocol of a `PortMapping`
+#[derive(Serialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Protocol {
+    #[default]
+    Tcp,
+    Udp,
+    Sctp,
+    Unknown,
+}
+
+/// containerd writes the protocol as the CRI enum number and CRI-O as its name.
+impl<'de> Deserialize<'de> for Protocol {
+    fn deserialize<D>(deserializer: D) -> Result<Protocol, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum NumOrStr {
+            Num(i64),
+            Str(String),
+        }
+        Ok(match NumOrStr::deserialize(deserializer)? {
+            NumOrStr::Num(0) => Protocol::Tcp,
+            NumOrStr::Num(1) => Protocol::Udp,
+            NumOrStr::Num(2) => Protocol::Sctp,
+            NumOrStr::Str(s) => match s.to_uppercase().as_str() {
+                "TCP" => Protocol::Tcp,
+                "UDP" => Protocol::Udp,
+                "SCTP" => Protocol::Sctp,
+                _ => Protocol::Unknown,
+            },
+            _ => Protocol::Unknown,
+        })
+    }
+}
@@ -0,0 +1,13 @@
+use crate::{Container, Image, Pod};
+use std::time::SystemTime;
+
+/// The pods, containers and images on a node as returned by `Cli::list_all`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrioSnapshot {
+    pub pods: Vec<Pod>,
+    /// All the containers including the stopped and exited ones
+    pub containers: Vec<Container>,
+    pub images: Vec<Image>,
+    /// When the lists were requested
+    pub timestamp: SystemTime,
+}
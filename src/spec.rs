@@ -0,0 +1,276 @@
+use crate::CrioError;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+/// The CRI `NamespaceMode` that shares a namespace with the host
+const NAMESPACE_MODE_NODE: i32 = 2;
+
+/// Builds the pod sandbox config passed to `Cli::runp`
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::PodBuilder;
+/// let spec = PodBuilder::new()
+///     .name("nginx-sandbox")
+///     .uid("hdishd83djaidwnduwk28bcsb")
+///     .dns_servers(vec!["10.96.0.10".to_string()])
+///     .build()
+///     .unwrap();
+/// assert_eq!(spec["metadata"]["namespace"], "default");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PodBuilder {
+    name: String,
+    namespace: String,
+    uid: String,
+    hostname: Option<String>,
+    labels: HashMap<String, String>,
+    annotations: HashMap<String, String>,
+    dns_servers: Vec<String>,
+    host_network: bool,
+}
+
+impl Default for PodBuilder {
+    fn default() -> PodBuilder {
+        PodBuilder {
+            name: String::new(),
+            namespace: "default".to_string(),
+            uid: String::new(),
+            hostname: None,
+            labels: HashMap::new(),
+            annotations: HashMap::new(),
+            dns_servers: Vec::new(),
+            host_network: false,
+        }
+    }
+}
+
+impl PodBuilder {
+    /// Returns a builder for a pod in the `default` namespace
+    pub fn new() -> PodBuilder {
+        PodBuilder::default()
+    }
+
+    /// Sets the name of the pod. Required.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets the kubernetes namespace of the pod
+    pub fn namespace(mut self, namespace: impl Into<String>) -> Self {
+        self.namespace = namespace.into();
+        self
+    }
+
+    /// Sets the uid of the pod. Required.
+    pub fn uid(mut self, uid: impl Into<String>) -> Self {
+        self.uid = uid.into();
+        self
+    }
+
+    /// Sets the hostname of the pod. The runtime uses the node hostname when it isn't set.
+    pub fn hostname(mut self, hostname: impl Into<String>) -> Self {
+        self.hostname = Some(hostname.into());
+        self
+    }
+
+    /// Sets the labels of the pod
+    pub fn labels(mut self, labels: HashMap<String, String>) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Sets the annotations of the pod
+    pub fn annotations(mut self, annotations: HashMap<String, String>) -> Self {
+        self.annotations = annotations;
+        self
+    }
+
+    /// Sets the nameservers written to the pod's resolv.conf
+    pub fn dns_servers(mut self, dns_servers: Vec<String>) -> Self {
+        self.dns_servers = dns_servers;
+        self
+    }
+
+    /// Sets whether the pod uses the network namespace of the node
+    pub fn host_network(mut self, host_network: bool) -> Self {
+        self.host_network = host_network;
+        self
+    }
+
+    /// Returns the config in the JSON format `crictl runp` reads
+    ///
+    /// Returns `CrioError::InvalidArgument` if the name, namespace or uid is empty
+    /// as the runtimes reject a sandbox without them.
+    pub fn build(self) -> Result<Value, CrioError> {
+        require("pod name", &self.name)?;
+        require("pod namespace", &self.namespace)?;
+        require("pod uid", &self.uid)?;
+        let mut spec = Map::new();
+        spec.insert(
+            "metadata".to_string(),
+            json!({
+                "name": self.name,
+                "namespace": self.namespace,
+                "uid": self.uid,
+                "attempt": 0
+            }),
+        );
+        if let Some(hostname) = self.hostname {
+            spec.insert("hostname".to_string(), json!(hostname));
+        }
+        spec.insert("labels".to_string(), json!(self.labels));
+        spec.insert("annotations".to_string(), json!(self.annotations));
+        if !self.dns_servers.is_empty() {
+            spec.insert(
+                "dns_config".to_string(),
+                json!({ "servers": self.dns_servers }),
+            );
+        }
+        if self.host_network {
+            spec.insert(
+                "linux".to_string(),
+                json!({
+                    "security_context": {
+                        "namespace_options": { "network": NAMESPACE_MODE_NODE }
+                    }
+                }),
+            );
+        }
+        Ok(Value::Object(spec))
+    }
+}
+
+/// Builds the container config passed to `crictl create`
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::ContainerBuilder;
+/// let spec = ContainerBuilder::new()
+///     .name("busybox")
+///     .image("docker.io/library/busybox:latest")
+///     .command(vec!["top".to_string()])
+///     .build()
+///     .unwrap();
+/// assert_eq!(spec["image"]["image"], "docker.io/library/busybox:latest");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ContainerBuilder {
+    name: String,
+    image: String,
+    command: Vec<String>,
+    args: Vec<String>,
+    working_dir: Option<String>,
+    envs: Vec<(String, String)>,
+    labels: HashMap<String, String>,
+    annotations: HashMap<String, String>,
+    log_path: Option<String>,
+}
+
+impl ContainerBuilder {
+    /// Returns an empty builder
+    pub fn new() -> ContainerBuilder {
+        ContainerBuilder::default()
+    }
+
+    /// Sets the name of the container. Required.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Sets the image the container runs. Required.
+    pub fn image(mut self, image: impl Into<String>) -> Self {
+        self.image = image.into();
+        self
+    }
+
+    /// Sets the entrypoint, replacing the one in the image
+    pub fn command(mut self, command: Vec<String>) -> Self {
+        self.command = command;
+        self
+    }
+
+    /// Sets the arguments passed to the entrypoint
+    pub fn args(mut self, args: Vec<String>) -> Self {
+        self.args = args;
+        self
+    }
+
+    /// Sets the directory the command runs in
+    pub fn working_dir(mut self, working_dir: impl Into<String>) -> Self {
+        self.working_dir = Some(working_dir.into());
+        self
+    }
+
+    /// Adds an environment variable. Variables are set in the order they are added.
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.envs.push((key.into(), value.into()));
+        self
+    }
+
+    /// Sets the labels of the container
+    pub fn labels(mut self, labels: HashMap<String, String>) -> Self {
+        self.labels = labels;
+        self
+    }
+
+    /// Sets the annotations of the container
+    pub fn annotations(mut self, annotations: HashMap<String, String>) -> Self {
+        self.annotations = annotations;
+        self
+    }
+
+    /// Sets the log file relative to the pod's log directory
+    pub fn log_path(mut self, log_path: impl Into<String>) -> Self {
+        self.log_path = Some(log_path.into());
+        self
+    }
+
+    /// Returns the config in the JSON format `crictl create` reads
+    ///
+    /// Returns `CrioError::InvalidArgument` if the name or image is empty.
+    pub fn build(self) -> Result<Value, CrioError> {
+        require("container name", &self.name)?;
+        require("container image", &self.image)?;
+        let mut spec = Map::new();
+        spec.insert(
+            "metadata".to_string(),
+            json!({ "name": self.name, "attempt": 0 }),
+        );
+        spec.insert("image".to_string(), json!({ "image": self.image }));
+        if !self.command.is_empty() {
+            spec.insert("command".to_string(), json!(self.command));
+        }
+        if !self.args.is_empty() {
+            spec.insert("args".to_string(), json!(self.args));
+        }
+        if let Some(working_dir) = self.working_dir {
+            spec.insert("working_dir".to_string(), json!(working_dir));
+        }
+        if !self.envs.is_empty() {
+            let envs: Vec<Value> = self
+                .envs
+                .into_iter()
+                .map(|(key, value)| json!({ "key": key, "value": value }))
+                .collect();
+            spec.insert("envs".to_string(), Value::Array(envs));
+        }
+        spec.insert("labels".to_string(), json!(self.labels));
+        spec.insert("annotations".to_string(), json!(self.annotations));
+        if let Some(log_path) = self.log_path {
+            spec.insert("log_path".to_string(), json!(log_path));
+        }
+        Ok(Value::Object(spec))
+    }
+}
+
+fn require(field: &str, value: &str) -> Result<(), CrioError> {
+    if value.is_empty() {
+        return Err(CrioError::InvalidArgument(format!("{} is required", field)));
+    }
+    Ok(())
+}
@@ -0,0 +1,75 @@
+use serde::Serialize;
+use serde_json::Value;
+
+/// Resource usage of a container as returned by `crictl stats`
+#[derive(Debug, Serialize, PartialEq, Eq, Clone)]
+pub struct ContainerStats {
+    pub id: String,
+    pub cpu_usage_nano_cores: u64,
+    /// The memory usage reported by the runtime.
+    /// Falls back to the working set size on runtimes that don't report usage.
+    pub memory_usage_bytes: u64,
+    /// Nanoseconds since the unix epoch
+    pub timestamp: i64,
+}
+
+/// Resource usage of a pod sandbox as returned by `crictl statsp`
+#[derive(Debug, Serialize, PartialEq, Eq, Clone)]
+pub struct PodStats {
+    pub id: String,
+    pub cpu_usage_nano_cores: u64,
+    /// The memory usage reported by the runtime.
+    /// Falls back to the working set size on runtimes that don't report usage.
+    pub memory_usage_bytes: u64,
+    /// Nanoseconds since the unix epoch
+    pub timestamp: i64,
+}
+
+impl ContainerStats {
+    pub(crate) fn from_value(v: &Value) -> ContainerStats {
+        ContainerStats {
+            id: v["attributes"]["id"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            cpu_usage_nano_cores: number(&v["cpu"]["usageNanoCores"]["value"]),
+            memory_usage_bytes: memory_usage(&v["memory"]),
+            timestamp: number(&v["cpu"]["timestamp"]),
+        }
+    }
+}
+
+impl PodStats {
+    pub(crate) fn from_value(v: &Value) -> PodStats {
+        let linux = &v["linux"];
+        PodStats {
+            id: v["attributes"]["id"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string(),
+            cpu_usage_nano_cores: number(&linux["cpu"]["usageNanoCores"]["value"]),
+            memory_usage_bytes: memory_usage(&linux["memory"]),
+            timestamp: number(&linux["cpu"]["timestamp"]),
+        }
+    }
+}
+
+fn memory_usage(memory: &Value) -> u64 {
+    match memory.get("usageBytes") {
+        Some(usage) => number(&usage["value"]),
+        None => number(&memory["workingSetBytes"]["value"]),
+    }
+}
+
+/// crictl encodes the stats values as either strings or numbers
+fn number<T: std::str::FromStr + Default + TryFrom<u64> + TryFrom<i64>>(v: &Value) -> T {
+    match v {
+        Value::String(s) => s.parse().unwrap_or_default(),
+        Value::Number(n) => match (n.as_u64(), n.as_i64()) {
+            (Some(u), _) => T::try_from(u).unwrap_or_default(),
+            (None, Some(i)) => T::try_from(i).unwrap_or_default(),
+            _ => T::default(),
+        },
+        _ => T::default(),
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{Cli, CommandOptions, CrictlExecutor, CrioError, MockCrictlExecutor};
+use std::ffi::OsString;
+use std::ops::Deref;
+use std::process::{Child, Output};
+use std::sync::Mutex;
+
+/// A crictl or ctr call and the result the executor gave for it
+pub type RecordedCall = (Vec<OsString>, Result<String, CrioError>);
+
+/// A `Cli` that answers from a `MockCrictlExecutor` and records every crictl call
+///
+/// The `Cli` calls are reached through `Deref`. The arguments recorded are the full ones passed
+/// to crictl, including the global flags the `Cli` adds, so tests can check how they were built.
+/// Calls that return the exit code such as exec are recorded with their stdout. Streaming calls
+/// such as `follow_logs` can't be answered by the mock and are recorded with their error.
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::{Cli, MockCrictlExecutor, TestCli};
+/// let executor = MockCrictlExecutor::new().respond(
+///     ["pods", "-o", "json", "--namespace", "kube-system"],
+///     Ok(r#"{"items": []}"#.to_string()),
+/// );
+/// let cli = TestCli::new(Cli::default(), executor);
+/// assert!(cli.pods_by_namespace("kube-system").unwrap().is_empty());
+/// let calls = cli.recorded_calls();
+/// assert_eq!(calls.len(), 1);
+/// assert_eq!(calls[0].0, ["pods", "-o", "json", "--namespace", "kube-system"]);
+/// ```
+#[derive(Debug)]
+pub struct TestCli {
+    cli: Cli<RecordingExecutor>,
+}
+
+impl TestCli {
+    /// Returns a `TestCli` with the settings of `cli` that answers crictl calls from `executor`
+    pub fn new(cli: Cli, executor: MockCrictlExecutor) -> TestCli {
+        TestCli {
+            cli: cli.with_executor(RecordingExecutor {
+                inner: executor,
+                calls: Mutex::new(vec![]),
+            }),
+        }
+    }
+
+    /// Returns a copy of the crictl calls made so far, oldest first
+    pub fn recorded_calls(&self) -> Vec<RecordedCall> {
+        self.cli
+            .executor
+            .calls
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .map(|(args, result)| {
+                let result = match result {
+                    Ok(output) => Ok(output.clone()),
+                    Err(e) => Err(e.duplicate()),
+                };
+                (args.clone(), result)
+            })
+            .collect()
+    }
+
+    /// Forgets the calls recorded so far
+    pub fn clear_recorded_calls(&self) {
+        self.cli
+            .executor
+            .calls
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clear();
+    }
+}
+
+impl Deref for TestCli {
+    type Target = Cli<RecordingExecutor>;
+
+    fn deref(&self) -> &Cli<RecordingExecutor> {
+        &self.cli
+    }
+}
+
+/// The executor of a `TestCli`, passing each call to a `MockCrictlExecutor` and keeping a copy of it
+#[derive(Debug)]
+pub struct RecordingExecutor {
+    inner: MockCrictlExecutor,
+    calls: Mutex<Vec<RecordedCall>>,
+}
+
+impl CrictlExecutor for RecordingExecutor {
+    fn execute(
+        &self,
+        args: &[OsString],
+        bin_path: &str,
+        options: &CommandOptions,
+    ) -> Result<String, CrioError> {
+        let result = self.inner.execute(args, bin_path, options);
+        self.record(args, result.as_ref().map(String::clone));
+        result
+    }
+
+    fn execute_output(
+        &self,
+        program: &str,
+        args: &[OsString],
+        bin_path: &str,
+        options: &CommandOptions,
+    ) -> Result<Output, CrioError> {
+        let result = self.inner.execute_output(program, args, bin_path, options);
+        self.record(
+            args,
+            result
+                .as_ref()
+                .map(|output| String::from_utf8_lossy(&output.stdout).into_owned()),
+        );
+        result
+    }
+
+    fn spawn(
+        &self,
+        args: &[OsString],
+        bin_path: &str,
+        options: &CommandOptions,
+        interactive: bool,
+    ) -> Result<Child, CrioError> {
+        let result = self.inner.spawn(args, bin_path, options, interactive);
+        self.record(args, result.as_ref().map(|_| String::new()));
+        result
+    }
+}
+
+impl RecordingExecutor {
+    fn record(&self, args: &[OsString], result: Result<String, &CrioError>) {
+        self.calls
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push((args.to_vec(), result.map_err(CrioError::duplicate)));
+    }
+}
@@ -0,0 +1,305 @@
+//! Helpers for pulling ids, names and digests out of crictl output
+
+use crate::{debug, Container, CrioError, Image, Pod};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::time::{Duration, SystemTime};
+
+/// Returns the id of a pod from an entry of `crictl pods` or the output of `inspect_pod`
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::util::pod_id;
+/// use serde_json::json;
+/// let pod = json!({ "id": "51cd8bdaa13a", "state": "SANDBOX_READY" });
+/// assert_eq!(pod_id(&pod).unwrap(), "51cd8bdaa13a");
+/// let inspect = json!({ "status": { "id": "51cd8bdaa13a" } });
+/// assert_eq!(pod_id(&inspect).unwrap(), "51cd8bdaa13a");
+/// assert!(pod_id(&json!({})).is_err());
+/// ```
+pub fn pod_id(v: &Value) -> Result<&str, CrioError> {
+    id(v, "pod")
+}
+
+/// Returns the id of a container from an entry of `crictl ps` or the output of `inspect_container`
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::util::container_id;
+/// use serde_json::json;
+/// let container = json!({ "id": "765312810c81", "state": "CONTAINER_RUNNING" });
+/// assert_eq!(container_id(&container).unwrap(), "765312810c81");
+/// assert!(container_id(&json!({ "id": 7 })).is_err());
+/// ```
+pub fn container_id(v: &Value) -> Result<&str, CrioError> {
+    id(v, "container")
+}
+
+/// Returns the id of an image from an entry of `crictl img`
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::util::image_id;
+/// use serde_json::json;
+/// let image = json!({ "id": "sha256:3b8adc6c30f4", "repoTags": [] });
+/// assert_eq!(image_id(&image).unwrap(), "sha256:3b8adc6c30f4");
+/// assert!(image_id(&json!(null)).is_err());
+/// ```
+pub fn image_id(v: &Value) -> Result<&str, CrioError> {
+    id(v, "image")
+}
+
+/// Returns the repo:tag references of an image from an entry of `crictl img`
+///
+/// Returns an empty vec when there are no tags, like for an image pulled by digest.
+/// The typed `Image` has these in `repo_tags`.
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::util::repo_tags;
+/// use serde_json::json;
+/// let image = json!({ "id": "sha256:3b8adc6c30f4", "repoTags": ["docker.io/library/busybox:latest"] });
+/// assert_eq!(repo_tags(&image), vec!["docker.io/library/busybox:latest"]);
+/// assert!(repo_tags(&json!({ "id": "sha256:3b8adc6c30f4", "repoTags": null })).is_empty());
+/// ```
+pub fn repo_tags(v: &Value) -> Vec<String> {
+    v["repoTags"]
+        .as_array()
+        .map(|tags| {
+            tags.iter()
+                .filter_map(|tag| tag.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Returns the kubernetes labels of a pod
+///
+/// The kubelet adds `io.kubernetes.pod.name`, `io.kubernetes.pod.namespace` and `io.kubernetes.pod.uid`
+/// alongside the labels set on the pod.
+pub fn pod_labels(pod: &Pod) -> &HashMap<String, String> {
+    &pod.labels
+}
+
+/// Returns the kubernetes annotations of a pod
+pub fn pod_annotations(pod: &Pod) -> &HashMap<String, String> {
+    &pod.annotations
+}
+
+/// Returns true if the pod has the label `key` set to `value`
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::util::pod_has_label;
+/// use libcrio::Pod;
+/// use serde_json::json;
+/// let pod: Pod = serde_json::from_value(json!({
+///     "id": "51cd8bdaa13a",
+///     "state": "SANDBOX_READY",
+///     "labels": { "app": "nginx" }
+/// }))
+/// .unwrap();
+/// assert!(pod_has_label(&pod, "app", "nginx"));
+/// assert!(!pod_has_label(&pod, "app", "redis"));
+/// assert!(!pod_has_label(&pod, "tier", "nginx"));
+/// ```
+pub fn pod_has_label(pod: &Pod, key: &str, value: &str) -> bool {
+    pod.labels.get(key).is_some_and(|v| v == value)
+}
+
+/// Returns when a pod sandbox was created
+///
+/// Returns `SystemTime::UNIX_EPOCH` when crictl didn't report a creation time.
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::util::pod_created_at;
+/// use libcrio::Pod;
+/// use serde_json::json;
+/// use std::time::{Duration, SystemTime};
+/// let pod: Pod = serde_json::from_value(json!({
+///     "id": "51cd8bdaa13a",
+///     "state": "SANDBOX_READY",
+///     "createdAt": "1618746959894040481"
+/// }))
+/// .unwrap();
+/// assert_eq!(
+///     pod_created_at(&pod),
+///     SystemTime::UNIX_EPOCH + Duration::new(1618746959, 894040481)
+/// );
+/// ```
+pub fn pod_created_at(pod: &Pod) -> SystemTime {
+    nanos_to_time(pod.created_at)
+}
+
+/// Returns when a container was created
+///
+/// Returns `SystemTime::UNIX_EPOCH` when crictl didn't report a creation time.
+pub fn container_created_at(container: &Container) -> SystemTime {
+    nanos_to_time(container.created_at)
+}
+
+/// Returns how long ago a pod sandbox was created
+///
+/// Returns `Duration::ZERO` when the creation time is ahead of the clock of this host.
+/// A pod without a creation time is as old as the unix epoch.
+pub fn pod_age(pod: &Pod) -> Duration {
+    SystemTime::now()
+        .duration_since(pod_created_at(pod))
+        .unwrap_or_default()
+}
+
+/// Returns the manifest digest an image was pulled with from `registry`
+///
+/// The `id` of an image is the digest of its config, so it is the same wherever the image came from.
+/// The manifest digest is the one a registry serves and signs, and it can differ between mirrors.
+/// It is taken from the entry of `repo_digests` whose host is `registry`.
+///
+/// # Arguments
+///
+/// * `image` - An image as returned by `Cli::image`
+///
+/// * `registry` - The registry host, with a port if it has one, e.g. "quay.io" or "registry.local:5000"
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::util::image_manifest_digest;
+/// use libcrio::Image;
+/// use serde_json::json;
+/// let image: Image = serde_json::from_value(json!({
+///     "id": "sha256:7d3a6f1e2c5b",
+///     "repoDigests": ["quay.io/sclorg/postgres@sha256:eeee5555"]
+/// }))
+/// .unwrap();
+/// assert_eq!(image_manifest_digest(&image, "quay.io"), Some("sha256:eeee5555"));
+/// assert_eq!(image_manifest_digest(&image, "docker.io"), None);
+/// ```
+pub fn image_manifest_digest<'a>(image: &'a Image, registry: &str) -> Option<&'a str> {
+    let registry = registry.trim_end_matches('/');
+    image.repo_digests.iter().find_map(|repo_digest| {
+        let (repo, digest) = repo_digest.split_once('@')?;
+        let (host, path) = repo.split_once('/')?;
+        (host == registry && !path.is_empty() && digest.starts_with("sha256:")).then_some(digest)
+    })
+}
+
+/// Returns the kubernetes name of a pod, or "" when crictl didn't report one
+///
+/// The same as `Pod::name`.
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::util::pod_name;
+/// use libcrio::Pod;
+/// use serde_json::json;
+/// let pod: Pod = serde_json::from_value(json!({
+///     "id": "51cd8bdaa13a",
+///     "metadata": { "name": "nginx", "namespace": "web" },
+///     "state": "SANDBOX_READY"
+/// }))
+/// .unwrap();
+/// assert_eq!(pod_name(&pod), "nginx");
+/// ```
+pub fn pod_name(pod: &Pod) -> &str {
+    pod.name()
+}
+
+/// Returns the kubernetes namespace of a pod, or "" when crictl didn't report one
+///
+/// The same as `Pod::namespace`.
+pub fn pod_namespace(pod: &Pod) -> &str {
+    pod.namespace()
+}
+
+/// Returns the name of a container, or "" when crictl didn't report one
+///
+/// The same as `Container::name`.
+pub fn container_name(container: &Container) -> &str {
+    container.name()
+}
+
+/// Returns the kubernetes name of a pod from an entry of `crictl pods` or the output of `inspect_pod`
+///
+/// Returns "" when there is no name in the metadata. `pod_name` does the same for a typed pod.
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::util::pod_name_from_value;
+/// use serde_json::json;
+/// let pod = json!({ "id": "51cd8bdaa13a", "metadata": { "name": "nginx", "namespace": "web" } });
+/// assert_eq!(pod_name_from_value(&pod), "nginx");
+/// assert_eq!(pod_name_from_value(&json!({ "id": "51cd8bdaa13a" })), "");
+/// ```
+pub fn pod_name_from_value(v: &Value) -> &str {
+    metadata_field(v, "pod", "name")
+}
+
+/// Returns the kubernetes namespace of a pod from an entry of `crictl pods` or the output of `inspect_pod`
+///
+/// Returns "" when there is no namespace in the metadata. `pod_namespace` does the same for a typed pod.
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::util::pod_namespace_from_value;
+/// use serde_json::json;
+/// let inspect = json!({ "status": { "id": "51cd8bdaa13a", "metadata": { "namespace": "web" } } });
+/// assert_eq!(pod_namespace_from_value(&inspect), "web");
+/// assert_eq!(pod_namespace_from_value(&json!({ "metadata": null })), "");
+/// ```
+pub fn pod_namespace_from_value(v: &Value) -> &str {
+    metadata_field(v, "pod", "namespace")
+}
+
+/// Returns the name of a container from an entry of `crictl ps` or the output of `inspect_container`
+///
+/// Returns "" when there is no name in the metadata. `container_name` does the same for a typed container.
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::util::container_name_from_value;
+/// use serde_json::json;
+/// let container = json!({ "id": "765312810c81", "metadata": { "name": "ubuntu" } });
+/// assert_eq!(container_name_from_value(&container), "ubuntu");
+/// assert_eq!(container_name_from_value(&json!({ "metadata": { "name": 7 } })), "");
+/// ```
+pub fn container_name_from_value(v: &Value) -> &str {
+    metadata_field(v, "container", "name")
+}
+
+/// Converts the nanoseconds since the unix epoch of a `created_at`, where 0 means it wasn't reported
+fn nanos_to_time(nanos: i64) -> SystemTime {
+    match u64::try_from(nanos) {
+        Ok(nanos) => SystemTime::UNIX_EPOCH + Duration::from_nanos(nanos),
+        Err(_) => SystemTime::UNIX_EPOCH,
+    }
+}
+
+/// Looks for a string field of `metadata` at the top level and then under `status`
+fn metadata_field<'a>(v: &'a Value, kind: &str, field: &str) -> &'a str {
+    v["metadata"][field]
+        .as_str()
+        .or_else(|| v["status"]["metadata"][field].as_str())
+        .unwrap_or_else(|| {
+            debug!("no {} {} in the metadata of {}", kind, field, v);
+            ""
+        })
+}
+
+/// Looks for a string `id` at the top level and then under `status` where inspect keeps it
+fn id<'a>(v: &'a Value, kind: &str) -> Result<&'a str, CrioError> {
+    v["id"]
+        .as_str()
+        .or_else(|| v["status"]["id"].as_str())
+        .ok_or_else(|| CrioError::NotFound(format!("no {} id in {}", kind, v)))
+}
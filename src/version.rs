@@ -0,0 +1,188 @@
+use crate::CrioError;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt;
+use std::str::FromStr;
+
+/// The version information returned by `Cli::version`
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeVersion {
+    /// The version of the crictl binary as reported by `crictl --version`
+    #[serde(default)]
+    pub crictl_version: String,
+    pub runtime_name: String,
+    pub runtime_version: String,
+    pub runtime_api_version: String,
+}
+
+impl fmt::Display for RuntimeVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "crictl {} talking to {} {} (CRI {})",
+            self.crictl_version, self.runtime_name, self.runtime_version, self.runtime_api_version
+        )
+    }
+}
+
+/// The version of the crictl binary, compared by semver precedence
+///
+/// Parsed from `crictl --version` output such as `crictl version v1.29.0`.
+/// Build metadata after a `+` is dropped as it doesn't affect the order.
+///
+/// # Examples
+///
+/// ```
+/// use libcrio::CrictlVersion;
+/// let rc: CrictlVersion = "v1.30.0-rc.1".parse().unwrap();
+/// assert_eq!(rc.pre, "rc.1");
+/// assert!(rc < CrictlVersion::new(1, 30, 0));
+/// assert!(rc > CrictlVersion::new(1, 29, 3));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CrictlVersion {
+    pub major: u64,
+    pub minor: u64,
+    pub patch: u64,
+    /// The pre-release e.g. "rc.1". Empty for a release.
+    pub pre: String,
+}
+
+impl CrictlVersion {
+    /// Returns the release version `major.minor.patch`
+    pub const fn new(major: u64, minor: u64, patch: u64) -> CrictlVersion {
+        CrictlVersion {
+            major,
+            minor,
+            patch,
+            pre: String::new(),
+        }
+    }
+}
+
+impl FromStr for CrictlVersion {
+    type Err = CrioError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || CrioError::InvalidArgument(format!("crictl version '{}'", s));
+        let version = s.trim().trim_start_matches('v');
+        let version = version.split_once('+').map_or(version, |(v, _)| v);
+        let (release, pre) = match version.split_once('-') {
+            Some((_, "")) => return Err(invalid()),
+            Some(split) => split,
+            None => (version, ""),
+        };
+        let mut numbers = release.split('.').map(|n| n.parse::<u64>());
+        let (Some(Ok(major)), Some(Ok(minor)), patch, None) = (
+            numbers.next(),
+            numbers.next(),
+            numbers.next(),
+            numbers.next(),
+        ) else {
+            return Err(invalid());
+        };
+        // crictl has always had a patch number but be lenient with "1.29"
+        let patch = match patch {
+            Some(p) => p.map_err(|_| invalid())?,
+            None => 0,
+        };
+        // As in semver a number can't have leading zeros, "rc.01" would otherwise sort the same as "rc.1"
+        if !pre.is_empty()
+            && pre
+                .split('.')
+                .any(|id| id.is_empty() || (id.len() > 1 && id.starts_with('0') && is_number(id)))
+        {
+            return Err(invalid());
+        }
+        Ok(CrictlVersion {
+            major,
+            minor,
+            patch,
+            pre: pre.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for CrictlVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)?;
+        if !self.pre.is_empty() {
+            write!(f, "-{}", self.pre)?;
+        }
+        Ok(())
+    }
+}
+
+impl Ord for CrictlVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.major, self.minor, self.patch)
+            .cmp(&(other.major, other.minor, other.patch))
+            .then_with(|| match (self.pre.is_empty(), other.pre.is_empty()) {
+                (true, true) => Ordering::Equal,
+                // A pre-release comes before its release
+                (true, false) => Ordering::Greater,
+                (false, true) => Ordering::Less,
+                (false, false) => cmp_pre(&self.pre, &other.pre),
+            })
+    }
+}
+
+impl PartialOrd for CrictlVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Returns true for an identifier made only of ASCII digits
+fn is_number(id: &str) -> bool {
+    id.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Returns the value of an identifier that is a number without leading zeros
+///
+/// Anything else is compared as text so two identifiers are only equal when their strings are,
+/// keeping `Ord` in line with the derived `Eq` for a `pre` set directly.
+fn numeric_id(id: &str) -> Option<u64> {
+    if is_number(id) && (id.len() == 1 || !id.starts_with('0')) {
+        id.parse().ok()
+    } else {
+        None
+    }
+}
+
+/// Compares pre-releases identifier by identifier, numbers numerically and before any text
+fn cmp_pre(a: &str, b: &str) -> Ordering {
+    let mut a_ids = a.split('.');
+    let mut b_ids = b.split('.');
+    loop {
+        let ord = match (a_ids.next(), b_ids.next()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a), Some(b)) => match (numeric_id(a), numeric_id(b)) {
+                (Some(a), Some(b)) => a.cmp(&b),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => a.cmp(b),
+            },
+        };
+        if ord != Ordering::Equal {
+            return ord;
+        }
+    }
+}
+
+/// Written as the version string so it can be pinned in a toml config
+impl Serialize for CrictlVersion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CrictlVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}